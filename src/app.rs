@@ -1,10 +1,135 @@
+use std::cell::Cell;
+use std::collections::HashSet;
 use std::time::Instant;
 use anyhow::Result;
 
 use crate::collectors::Collectors;
+use crate::collectors::watch::WatchTarget;
+use crate::collectors::process::{flat_rows, subtree_pids, tree_rows, ProcessFilter, ProcessInfo, ProcessRow, SortColumn};
 use crate::config::Config;
+use crate::control::{self, IoClass, Signal};
 use crate::themes::{Theme, THEMES};
 
+/// Top-level screens, cycled with Tab
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum View {
+    #[default]
+    Dashboard,
+    Processes,
+    Users,
+    Services,
+    Interrupts,
+    Disks,
+}
+
+impl View {
+    pub const ALL: [View; 6] = [
+        View::Dashboard,
+        View::Processes,
+        View::Users,
+        View::Services,
+        View::Interrupts,
+        View::Disks,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            View::Dashboard => "Dashboard",
+            View::Processes => "Processes",
+            View::Users => "Users",
+            View::Services => "Services",
+            View::Interrupts => "IRQs",
+            View::Disks => "Disks",
+        }
+    }
+}
+
+/// Selection state for the list views (users, cgroups, IRQs, filesystems)
+#[derive(Default)]
+pub struct ListView {
+    /// Selection follows the row key (e.g. user name) across refreshes
+    pub selected_key: Option<String>,
+    pub selected_index: usize,
+    pub scroll_offset: Cell<usize>,
+    pub page_size: Cell<usize>,
+}
+
+impl ListView {
+    /// Row index of the selection within `keys`
+    pub fn selected_row(&self, keys: &[String]) -> Option<usize> {
+        if keys.is_empty() {
+            return None;
+        }
+        self.selected_key
+            .as_ref()
+            .and_then(|key| keys.iter().position(|k| k == key))
+            .or(Some(self.selected_index.min(keys.len() - 1)))
+    }
+
+    fn move_by(&mut self, keys: &[String], delta: isize) {
+        let Some(current) = self.selected_row(keys) else {
+            return;
+        };
+        let target = current.saturating_add_signed(delta).min(keys.len() - 1);
+        self.selected_key = Some(keys[target].clone());
+        self.selected_index = target;
+    }
+}
+
+/// State of the full process table
+#[derive(Default)]
+pub struct ProcessView {
+    pub sort_column: SortColumn,
+    pub sort_descending: bool,
+    /// Show parent/child hierarchy instead of a flat list
+    pub tree_mode: bool,
+    /// PIDs whose subtree is folded in tree mode
+    pub collapsed: HashSet<u32>,
+    /// Raw text of the `/` search and its parsed form
+    pub filter_input: String,
+    pub filter: ProcessFilter,
+    /// The `/` prompt has keyboard focus
+    pub filter_editing: bool,
+    pub hide_kernel_threads: bool,
+    pub only_own_user: bool,
+    /// Selection follows the PID across refreshes
+    pub selected_pid: Option<u32>,
+    /// Fallback when the selected PID disappears
+    pub selected_index: usize,
+    /// First visible row, adjusted while rendering
+    pub scroll_offset: Cell<usize>,
+    /// Visible row count from the last render (for paging)
+    pub page_size: Cell<usize>,
+}
+
+/// What a text prompt is asking for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Nice,
+    IoNice,
+    Affinity,
+}
+
+impl InputKind {
+    pub fn prompt(self) -> &'static str {
+        match self {
+            InputKind::Nice => "Nice (-20..19)",
+            InputKind::IoNice => "I/O class[:level] (rt/be/idle, 0-7)",
+            InputKind::Affinity => "CPU list (e.g. 0-3,6)",
+        }
+    }
+}
+
+/// Modal popups drawn over the current view
+pub enum Dialog {
+    /// Choose a signal for a process (or its whole subtree)
+    SignalMenu { pid: u32, name: String, subtree: bool, selected: usize },
+    /// Last chance before the signal goes out
+    ConfirmSignal { pid: u32, name: String, pids: Vec<u32>, signal: Signal },
+    /// Free-text value for the selected process
+    Input { kind: InputKind, pid: u32, name: String, buffer: String },
+}
+
 /// Application state
 pub struct App {
     pub config: Config,
@@ -13,6 +138,13 @@ pub struct App {
     pub theme_index: usize,
     pub should_quit: bool,
     pub show_help: bool,
+    pub view: View,
+    pub process_view: ProcessView,
+    pub user_view: ListView,
+    pub service_view: ListView,
+    pub irq_view: ListView,
+    pub disk_view: ListView,
+    pub dialog: Option<Dialog>,
     pub status_message: Option<String>,
     status_message_time: Option<Instant>,
 }
@@ -33,6 +165,16 @@ impl App {
             theme_index,
             should_quit: false,
             show_help: false,
+            view: View::default(),
+            process_view: ProcessView {
+                sort_descending: SortColumn::default().default_descending(),
+                ..Default::default()
+            },
+            user_view: ListView::default(),
+            service_view: ListView::default(),
+            irq_view: ListView::default(),
+            disk_view: ListView::default(),
+            dialog: None,
             status_message: None,
             status_message_time: None,
         })
@@ -40,7 +182,12 @@ impl App {
 
     /// Update all collectors
     pub fn update(&mut self) -> Result<()> {
-        self.collectors.update()
+        self.collectors.update()?;
+
+        // Forget folded subtrees of processes that have exited
+        let processes = &self.collectors.process.data.processes;
+        self.process_view.collapsed.retain(|pid| processes.iter().any(|p| p.pid == *pid));
+        Ok(())
     }
 
     /// Force immediate refresh
@@ -60,6 +207,407 @@ impl App {
         self.show_help = !self.show_help;
     }
 
+    /// Switch to the next view
+    pub fn next_view(&mut self) {
+        let idx = View::ALL.iter().position(|&v| v == self.view).unwrap_or(0);
+        self.view = View::ALL[(idx + 1) % View::ALL.len()];
+    }
+
+    /// Switch to the previous view
+    pub fn prev_view(&mut self) {
+        let idx = View::ALL.iter().position(|&v| v == self.view).unwrap_or(0);
+        self.view = View::ALL[(idx + View::ALL.len() - 1) % View::ALL.len()];
+    }
+
+    /// Processes that pass the search and the kernel-thread / own-user toggles
+    pub fn filtered_processes(&self) -> Vec<&ProcessInfo> {
+        let view = &self.process_view;
+        let own_uid = self.collectors.process.own_uid;
+        self.collectors.process.data.processes
+            .iter()
+            .filter(|p| !(view.hide_kernel_threads && p.kernel_thread))
+            .filter(|p| !view.only_own_user || (own_uid.is_some() && p.uid == own_uid))
+            .filter(|p| view.filter.matches(p))
+            .collect()
+    }
+
+    /// Whether anything is hiding processes from the table
+    pub fn process_filter_active(&self) -> bool {
+        let view = &self.process_view;
+        view.filter.is_active() || view.hide_kernel_threads || view.only_own_user
+    }
+
+    /// Rows of the process table in display order
+    pub fn process_rows(&self) -> Vec<ProcessRow<'_>> {
+        let list = self.filtered_processes();
+        let view = &self.process_view;
+        if view.tree_mode {
+            tree_rows(&list, view.sort_column, view.sort_descending, &view.collapsed)
+        } else {
+            flat_rows(&list, view.sort_column, view.sort_descending)
+        }
+    }
+
+    /// Row index of the selected process in the visible list
+    pub fn selected_row(&self, rows: &[ProcessRow]) -> Option<usize> {
+        if rows.is_empty() {
+            return None;
+        }
+        let view = &self.process_view;
+        view.selected_pid
+            .and_then(|pid| rows.iter().position(|r| r.info.pid == pid))
+            .or(Some(view.selected_index.min(rows.len() - 1)))
+    }
+
+    /// PID of the selected row, if any
+    pub fn selected_pid(&self) -> Option<u32> {
+        let rows = self.process_rows();
+        self.selected_row(&rows).map(|i| rows[i].info.pid)
+    }
+
+    /// Move the selection by `delta` rows (negative = up)
+    pub fn move_selection(&mut self, delta: isize) {
+        let rows = self.process_rows();
+        let Some(current) = self.selected_row(&rows) else {
+            return;
+        };
+        let target = current.saturating_add_signed(delta).min(rows.len() - 1);
+        let pid = rows[target].info.pid;
+        self.process_view.selected_pid = Some(pid);
+        self.process_view.selected_index = target;
+
+        // The detail pane follows the selection
+        let inspected = self.collectors.process.inspected.as_ref().map(|i| i.pid);
+        if inspected.is_some_and(|old| old != pid) {
+            self.collectors.process.inspect(Some(pid));
+        }
+    }
+
+    /// Open or close the detail pane for the selected process
+    pub fn toggle_details(&mut self) {
+        if self.collectors.process.inspected.is_some() {
+            self.collectors.process.inspect(None);
+        } else {
+            let pid = self.selected_pid();
+            self.collectors.process.inspect(pid);
+        }
+    }
+
+    /// Jump to the first or last row
+    pub fn select_edge(&mut self, last: bool) {
+        let delta = if last { isize::MAX } else { isize::MIN };
+        self.move_selection(delta);
+    }
+
+    /// Move the selection one page up or down
+    pub fn page_selection(&mut self, down: bool) {
+        let page = self.process_view.page_size.get().max(1) as isize;
+        self.move_selection(if down { page } else { -page });
+    }
+
+    /// Cycle the process sort column
+    pub fn cycle_sort_column(&mut self, forward: bool) {
+        let view = &mut self.process_view;
+        view.sort_column = if forward {
+            view.sort_column.next()
+        } else {
+            view.sort_column.prev()
+        };
+        view.sort_descending = view.sort_column.default_descending();
+    }
+
+    /// Flip the process sort direction
+    pub fn toggle_sort_order(&mut self) {
+        self.process_view.sort_descending = !self.process_view.sort_descending;
+    }
+
+    /// Switch between flat list and tree
+    pub fn toggle_tree_mode(&mut self) {
+        self.process_view.tree_mode = !self.process_view.tree_mode;
+    }
+
+    /// Fold or unfold the selected subtree (`None` toggles)
+    pub fn set_selected_collapsed(&mut self, collapse: Option<bool>) {
+        if !self.process_view.tree_mode {
+            return;
+        }
+        let Some(pid) = self.selected_pid() else {
+            return;
+        };
+        let collapsed = &mut self.process_view.collapsed;
+        let fold = collapse.unwrap_or(!collapsed.contains(&pid));
+        if fold {
+            collapsed.insert(pid);
+        } else {
+            collapsed.remove(&pid);
+        }
+    }
+
+    /// Row keys of the current list view, in display order
+    pub fn list_keys(&self) -> Vec<String> {
+        match self.view {
+            View::Users => self.collectors.process.data.users.iter().map(|u| u.user.clone()).collect(),
+            View::Services => self.collectors.process.data.cgroups.iter().map(|c| c.path.clone()).collect(),
+            View::Interrupts => self.collectors.interrupts.data.irqs.iter().map(|i| i.irq.clone()).collect(),
+            View::Disks => self.collectors.disk.filesystems.iter().map(|f| f.mount_point.clone()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn list_view(&self) -> Option<&ListView> {
+        match self.view {
+            View::Users => Some(&self.user_view),
+            View::Services => Some(&self.service_view),
+            View::Interrupts => Some(&self.irq_view),
+            View::Disks => Some(&self.disk_view),
+            _ => None,
+        }
+    }
+
+    fn list_view_mut(&mut self) -> Option<&mut ListView> {
+        match self.view {
+            View::Users => Some(&mut self.user_view),
+            View::Services => Some(&mut self.service_view),
+            View::Interrupts => Some(&mut self.irq_view),
+            View::Disks => Some(&mut self.disk_view),
+            _ => None,
+        }
+    }
+
+    /// Move the selection of the current list view (`isize::MIN`/`MAX` jump to the ends)
+    pub fn move_list_selection(&mut self, delta: isize) {
+        let keys = self.list_keys();
+        if let Some(list) = self.list_view_mut() {
+            list.move_by(&keys, delta);
+        }
+    }
+
+    /// Page through the current list view
+    pub fn page_list_selection(&mut self, down: bool) {
+        let page = self.list_view().map(|l| l.page_size.get()).unwrap_or(1).max(1) as isize;
+        self.move_list_selection(if down { page } else { -page });
+    }
+
+    /// Enter on a list row: show that group's processes, or put a filesystem in the disk panel
+    pub fn drill_down(&mut self) {
+        let keys = self.list_keys();
+        let Some(key) = self.list_view().and_then(|l| l.selected_row(&keys)).map(|i| keys[i].clone()) else {
+            return;
+        };
+        let filter = match self.view {
+            View::Users => format!("user:{}", key),
+            View::Services => format!("cgroup:{}", key),
+            View::Disks => {
+                self.collectors.disk.select(&key);
+                self.set_status(format!("✓ Disk panel shows {}", key));
+                self.config.disk_mount = Some(key);
+                return;
+            }
+            _ => return,
+        };
+        let view = &mut self.process_view;
+        view.filter = ProcessFilter::parse(&filter);
+        view.filter_input = filter;
+        self.view = View::Processes;
+    }
+
+    /// Focus the `/` search prompt
+    pub fn start_filter(&mut self) {
+        self.process_view.filter_editing = true;
+    }
+
+    /// Leave the search prompt; `clear` also drops the filter
+    pub fn finish_filter(&mut self, clear: bool) {
+        let view = &mut self.process_view;
+        view.filter_editing = false;
+        if clear {
+            view.filter_input.clear();
+            view.filter = ProcessFilter::None;
+        }
+    }
+
+    /// Edit the search text; the table re-filters as you type
+    pub fn filter_char(&mut self, c: Option<char>) {
+        let view = &mut self.process_view;
+        match c {
+            Some(c) => view.filter_input.push(c),
+            None => {
+                view.filter_input.pop();
+            }
+        }
+        view.filter = ProcessFilter::parse(&view.filter_input);
+    }
+
+    pub fn toggle_kernel_threads(&mut self) {
+        self.process_view.hide_kernel_threads = !self.process_view.hide_kernel_threads;
+    }
+
+    pub fn toggle_own_user(&mut self) {
+        self.process_view.only_own_user = !self.process_view.only_own_user;
+    }
+
+    /// Switch process CPU% between per-core and whole-machine scaling
+    pub fn toggle_cpu_mode(&mut self) {
+        let mode = self.collectors.process.cpu_mode.toggle();
+        self.collectors.process.cpu_mode = mode;
+        self.config.cpu_mode = mode;
+        self.set_status(format!("✓ Process CPU%: {}", mode.label()));
+    }
+
+    /// Watch / unwatch the selected process, by PID or (`by_name`) by its name
+    pub fn toggle_watch(&mut self, by_name: bool) {
+        let Some((pid, name)) = self.selected_process() else {
+            return;
+        };
+        let process = &mut self.collectors.process;
+        let message = if !by_name && process.unwatch_pid(pid) {
+            format!("✓ Stopped watching PID {}", pid)
+        } else {
+            let (target, label) = if by_name {
+                match WatchTarget::parse(&name) {
+                    Some(target) => (target, format!("\"{}\"", name)),
+                    None => return,
+                }
+            } else {
+                (WatchTarget::Pid(pid), format!("PID {}", pid))
+            };
+            if process.toggle_watch(target) {
+                format!("✓ Watching {}", label)
+            } else {
+                format!("✓ Stopped watching {}", label)
+            }
+        };
+        // Only watches added here are saved; `--watch` ones stay for the session
+        let specs: Vec<String> = process.watched.iter().map(|w| w.target.spec()).collect();
+        self.config.cli_watch.retain(|spec| specs.contains(spec));
+        self.config.watch = specs.into_iter().filter(|spec| !self.config.cli_watch.contains(spec)).collect();
+        self.set_status(message);
+    }
+
+    /// Selected process as (pid, name)
+    fn selected_process(&self) -> Option<(u32, String)> {
+        let rows = self.process_rows();
+        self.selected_row(&rows).map(|i| (rows[i].info.pid, rows[i].info.name.clone()))
+    }
+
+    /// Open the signal picker for the selected process
+    pub fn open_signal_menu(&mut self, subtree: bool) {
+        if let Some((pid, name)) = self.selected_process() {
+            self.dialog = Some(Dialog::SignalMenu { pid, name, subtree, selected: 0 });
+        }
+    }
+
+    /// Open a text prompt for the selected process, prefilled with the current value
+    pub fn open_input(&mut self, kind: InputKind) {
+        let Some((pid, name)) = self.selected_process() else {
+            return;
+        };
+        let buffer = match kind {
+            InputKind::Nice => control::get_nice(pid).map(|n| n.to_string()).unwrap_or_default(),
+            InputKind::IoNice => String::new(),
+            InputKind::Affinity => control::get_affinity(pid)
+                .map(|cpus| control::format_cpu_list(&cpus))
+                .unwrap_or_default(),
+        };
+        self.dialog = Some(Dialog::Input { kind, pid, name, buffer });
+    }
+
+    pub fn close_dialog(&mut self) {
+        self.dialog = None;
+    }
+
+    /// Move the highlight in a menu dialog
+    pub fn dialog_move(&mut self, delta: isize) {
+        if let Some(Dialog::SignalMenu { selected, .. }) = &mut self.dialog {
+            let len = Signal::ALL.len() as isize;
+            *selected = (*selected as isize + delta).rem_euclid(len) as usize;
+        }
+    }
+
+    /// Type a character into a prompt
+    pub fn dialog_char(&mut self, c: char) {
+        if let Some(Dialog::Input { buffer, .. }) = &mut self.dialog {
+            buffer.push(c);
+        }
+    }
+
+    pub fn dialog_backspace(&mut self) {
+        if let Some(Dialog::Input { buffer, .. }) = &mut self.dialog {
+            buffer.pop();
+        }
+    }
+
+    /// Enter / y: advance the dialog or carry out its action
+    pub fn dialog_confirm(&mut self) {
+        let Some(dialog) = self.dialog.take() else {
+            return;
+        };
+        match dialog {
+            Dialog::SignalMenu { pid, name, subtree, selected } => {
+                let pids = if subtree {
+                    subtree_pids(&self.collectors.process.data.processes, pid)
+                } else {
+                    vec![pid]
+                };
+                let signal = Signal::ALL[selected];
+                self.dialog = Some(Dialog::ConfirmSignal { pid, name, pids, signal });
+            }
+            Dialog::ConfirmSignal { pid, name, pids, signal } => {
+                let errors: Vec<String> = pids
+                    .iter()
+                    .filter_map(|&p| control::send_signal(p, signal).err())
+                    .map(|e| e.to_string())
+                    .collect();
+                let msg = match errors.first() {
+                    None if pids.len() > 1 => format!("✓ {} sent to {} ({} processes)", signal.name(), name, pids.len()),
+                    None => format!("✓ {} sent to {} ({})", signal.name(), name, pid),
+                    Some(first) if errors.len() > 1 => format!("✗ {} ({} failures)", first, errors.len()),
+                    Some(first) => format!("✗ {}", first),
+                };
+                self.set_status(msg);
+            }
+            Dialog::Input { kind, pid, name, buffer } => {
+                self.apply_input(kind, pid, &name, &buffer);
+            }
+        }
+    }
+
+    fn apply_input(&mut self, kind: InputKind, pid: u32, name: &str, value: &str) {
+        let result = match kind {
+            InputKind::Nice => match value.trim().parse::<i32>() {
+                Ok(nice) if (-20..=19).contains(&nice) => {
+                    control::set_nice(pid, nice).map(|_| format!("nice {}", nice))
+                }
+                _ => return self.set_status(format!("✗ Invalid nice value: {}", value)),
+            },
+            InputKind::IoNice => match IoClass::parse(value) {
+                Some((class, level)) => control::set_ionice(pid, class, level)
+                    .map(|_| format!("I/O class {} level {}", class.label(), level)),
+                None => return self.set_status(format!("✗ Invalid I/O class: {}", value)),
+            },
+            InputKind::Affinity => {
+                let cpu_count = self.collectors.cpu.data.logical_cores.max(1);
+                match control::parse_cpu_list(value, cpu_count) {
+                    Some(cpus) => control::set_affinity(pid, &cpus)
+                        .map(|_| format!("affinity {}", control::format_cpu_list(&cpus))),
+                    None => {
+                        return self.set_status(format!("✗ Invalid CPU list: {} (CPUs 0-{})", value, cpu_count - 1))
+                    }
+                }
+            }
+        };
+        match result {
+            Ok(what) => self.set_status(format!("✓ {} ({}) set to {}", name, pid, what)),
+            Err(e) => self.set_status(format!("✗ {}", e)),
+        }
+    }
+
+    /// Show a message in the footer for a few seconds
+    pub fn set_status(&mut self, msg: String) {
+        self.status_message = Some(msg);
+        self.status_message_time = Some(Instant::now());
+    }
+
     /// Increase refresh rate (slower updates)
     pub fn increase_refresh_rate(&mut self) {
         // Use predefined steps for clean values
@@ -93,16 +641,15 @@ impl App {
         match self.config.save() {
             Ok(_) => {
                 if let Some(path) = Config::default_path() {
-                    self.status_message = Some(format!("✓ Saved to {}", path.display()));
+                    self.set_status(format!("✓ Saved to {}", path.display()));
                 } else {
-                    self.status_message = Some("✓ Config saved!".to_string());
+                    self.set_status("✓ Config saved!".to_string());
                 }
             }
             Err(e) => {
-                self.status_message = Some(format!("✗ Save failed: {}", e));
+                self.set_status(format!("✗ Save failed: {}", e));
             }
         }
-        self.status_message_time = Some(Instant::now());
     }
 
     /// Clear status message if it's been shown long enough (3 seconds)
@@ -0,0 +1,185 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What kind of workload a cgroup belongs to, derived from its path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CgroupKind {
+    Root,
+    Service,
+    Scope,
+    Slice,
+    Docker,
+    Podman,
+    Kubernetes,
+    Other,
+}
+
+impl CgroupKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            CgroupKind::Root => "root",
+            CgroupKind::Service => "service",
+            CgroupKind::Scope => "scope",
+            CgroupKind::Slice => "slice",
+            CgroupKind::Docker => "docker",
+            CgroupKind::Podman => "podman",
+            CgroupKind::Kubernetes => "k8s",
+            CgroupKind::Other => "cgroup",
+        }
+    }
+}
+
+/// Processes of one cgroup and the cgroup's own accounting
+#[derive(Clone)]
+pub struct CgroupUsage {
+    pub path: String,
+    pub name: String,
+    pub kind: CgroupKind,
+    pub processes: usize,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    /// memory.current / memory.max (None when unlimited or not cgroup v2)
+    pub memory_current: Option<u64>,
+    pub memory_max: Option<u64>,
+    /// cpu.stat throttling counters (cgroup v2)
+    pub nr_periods: u64,
+    pub nr_throttled: u64,
+    pub throttled_usec: u64,
+    /// Time spent throttled per second since the previous refresh (ms/s)
+    pub throttled_ms_per_sec: f64,
+}
+
+impl CgroupUsage {
+    pub fn new(path: &str) -> Self {
+        let (kind, name) = describe(path);
+        Self {
+            path: path.to_string(),
+            name,
+            kind,
+            processes: 0,
+            cpu_percent: 0.0,
+            memory_bytes: 0,
+            memory_current: None,
+            memory_max: None,
+            nr_periods: 0,
+            nr_throttled: 0,
+            throttled_usec: 0,
+            throttled_ms_per_sec: 0.0,
+        }
+    }
+
+    /// Fill in memory.current, memory.max and cpu.stat from the cgroup v2 tree
+    pub fn read_controller_files(&mut self, root: &Path) {
+        let dir = root.join(self.path.trim_start_matches('/'));
+        self.memory_current = read_u64(&dir.join("memory.current"));
+        self.memory_max = read_u64(&dir.join("memory.max"));
+        if let Ok(stat) = fs::read_to_string(dir.join("cpu.stat")) {
+            for line in stat.lines() {
+                let mut parts = line.split_whitespace();
+                let (Some(key), Some(value)) = (parts.next(), parts.next().and_then(|v| v.parse().ok())) else {
+                    continue;
+                };
+                match key {
+                    "nr_periods" => self.nr_periods = value,
+                    "nr_throttled" => self.nr_throttled = value,
+                    "throttled_usec" => self.throttled_usec = value,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Mount point of the unified (v2) hierarchy, if any
+pub fn unified_root() -> Option<PathBuf> {
+    ["/sys/fs/cgroup", "/sys/fs/cgroup/unified"]
+        .iter()
+        .map(PathBuf::from)
+        .find(|p| p.join("cgroup.controllers").exists())
+}
+
+/// Cgroup path of a process: the v2 entry, or systemd's v1 hierarchy when v2 is unused
+pub fn read_cgroup_path(pid: u32) -> Option<String> {
+    let content = fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    let mut unified = None;
+    let mut systemd = None;
+    for line in content.lines() {
+        let mut parts = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        if id == "0" && controllers.is_empty() {
+            unified = Some(path);
+        } else if controllers == "name=systemd" {
+            systemd = Some(path);
+        }
+    }
+    match (unified, systemd) {
+        (Some("/"), Some(v1)) => Some(v1.to_string()),
+        (Some(v2), _) => Some(v2.to_string()),
+        (None, v1) => v1.map(str::to_string),
+    }
+}
+
+/// Human name for a cgroup path: unit names, container IDs, pod/container pairs
+pub fn describe(path: &str) -> (CgroupKind, String) {
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    let Some(&last) = parts.last() else {
+        return (CgroupKind::Root, "/".to_string());
+    };
+
+    if parts.iter().any(|p| p.starts_with("kubepods")) {
+        let pod = parts
+            .iter()
+            .map(|p| p.trim_end_matches(".slice"))
+            .find_map(|p| p.rsplit_once("-pod").map(|(_, uid)| uid).or_else(|| p.strip_prefix("pod")))
+            .map(|uid| short_id(&uid.replace('_', "-")));
+        let container = container_id(last);
+        let name = match (pod, container) {
+            (Some(pod), Some(c)) => format!("pod {} / {}", pod, c),
+            (Some(pod), None) => format!("pod {}", pod),
+            (None, _) => last.to_string(),
+        };
+        return (CgroupKind::Kubernetes, name);
+    }
+
+    // Podman nests the container's processes below the libpod scope
+    if let Some(scope) = parts.iter().find(|p| p.starts_with("libpod-") && !p.starts_with("libpod-conmon")) {
+        let id = container_id(scope).unwrap_or_else(|| scope.to_string());
+        return (CgroupKind::Podman, format!("podman {}", id));
+    }
+    if let Some(scope) = parts.iter().find(|p| p.starts_with("docker-")) {
+        let id = container_id(scope).unwrap_or_else(|| scope.to_string());
+        return (CgroupKind::Docker, format!("docker {}", id));
+    }
+    if parts.len() >= 2 && parts[parts.len() - 2] == "docker" {
+        return (CgroupKind::Docker, format!("docker {}", short_id(last)));
+    }
+
+    // Name the group after the innermost systemd unit
+    let unit = parts.iter().rev().find(|p| p.contains('.')).copied().unwrap_or(last);
+    let kind = if unit.ends_with(".service") {
+        CgroupKind::Service
+    } else if unit.ends_with(".scope") {
+        CgroupKind::Scope
+    } else if unit.ends_with(".slice") {
+        CgroupKind::Slice
+    } else {
+        CgroupKind::Other
+    };
+    (kind, unit.to_string())
+}
+
+/// Container ID from "docker-<id>.scope", "cri-containerd-<id>.scope", "crio-<id>" or a bare ID
+fn container_id(component: &str) -> Option<String> {
+    let id = component.trim_end_matches(".scope").rsplit('-').next()?;
+    (id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit())).then(|| short_id(id))
+}
+
+fn short_id(id: &str) -> String {
+    id.chars().take(12).collect()
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
@@ -1,7 +1,176 @@
 use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::fs;
 use sysinfo::System;
 
 use super::History60;
+use crate::control;
+
+/// cpufreq state of one logical CPU
+#[derive(Clone, Default)]
+pub struct CoreFrequency {
+    pub current_mhz: u64,
+    /// Policy limits (scaling_min/max_freq); 0 when cpufreq is unavailable
+    pub min_mhz: u64,
+    pub max_mhz: u64,
+    /// cpuinfo_max_freq, what the hardware allows regardless of policy
+    pub hardware_max_mhz: u64,
+    /// base_frequency (intel_pstate only): the guaranteed clock under load
+    pub base_mhz: Option<u64>,
+    pub governor: String,
+    pub driver: String,
+    /// energy_performance_preference (intel_pstate / amd-pstate only)
+    pub energy_preference: Option<String>,
+}
+
+/// Where a logical CPU sits: socket, physical core, NUMA node and SMT siblings
+#[derive(Clone, Default)]
+pub struct CpuTopology {
+    /// physical_package_id
+    pub socket: u32,
+    /// core_id (only unique within a socket)
+    pub core_id: u32,
+    pub node: Option<u32>,
+    /// Logical CPUs sharing this physical core, this one included
+    pub siblings: Vec<usize>,
+}
+
+/// Why a CPU is running below its rated speed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrottleReason {
+    /// thermal_throttle/*_throttle_count went up
+    Thermal,
+    /// thermal_throttle/*_power_limit_count went up
+    PowerLimit,
+    /// scaling_max_freq lowered below its startup value (thermal daemons, power capping),
+    /// or a policy cap holding a busy core below its base clock
+    FrequencyCap,
+    /// A busy core clocked below its base frequency (or 60% of its maximum)
+    LowClock,
+}
+
+impl ThrottleReason {
+    pub const ALL: [ThrottleReason; 4] = [
+        ThrottleReason::Thermal,
+        ThrottleReason::PowerLimit,
+        ThrottleReason::FrequencyCap,
+        ThrottleReason::LowClock,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThrottleReason::Thermal => "thermal",
+            ThrottleReason::PowerLimit => "power limit",
+            ThrottleReason::FrequencyCap => "freq cap",
+            ThrottleReason::LowClock => "low clock",
+        }
+    }
+}
+
+/// One throttling episode: it starts when a reason appears and lasts while it persists
+#[derive(Clone, Debug)]
+pub struct ThrottleEvent {
+    pub reason: ThrottleReason,
+    /// Logical CPUs affected when the episode started
+    pub cpus: Vec<usize>,
+    pub started: DateTime<Local>,
+    pub last_seen: DateTime<Local>,
+}
+
+/// Throttling episodes kept for display
+const MAX_THROTTLE_EVENTS: usize = 100;
+
+/// Physical cores of one socket / NUMA node, each as its logical CPUs
+#[derive(Clone, Default)]
+pub struct CoreGroup {
+    pub socket: u32,
+    pub node: Option<u32>,
+    pub cores: Vec<Vec<usize>>,
+}
+
+/// /proc/stat time categories (idle excluded)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuTimeKind {
+    User,
+    Nice,
+    System,
+    Irq,
+    SoftIrq,
+    IoWait,
+    Steal,
+}
+
+impl CpuTimeKind {
+    /// Stacking order: work first, then time lost to waiting or the hypervisor
+    pub const ALL: [CpuTimeKind; 7] = [
+        CpuTimeKind::User,
+        CpuTimeKind::Nice,
+        CpuTimeKind::System,
+        CpuTimeKind::Irq,
+        CpuTimeKind::SoftIrq,
+        CpuTimeKind::IoWait,
+        CpuTimeKind::Steal,
+    ];
+
+    /// top-style abbreviation
+    pub fn short(self) -> &'static str {
+        match self {
+            CpuTimeKind::User => "us",
+            CpuTimeKind::Nice => "ni",
+            CpuTimeKind::System => "sy",
+            CpuTimeKind::Irq => "hi",
+            CpuTimeKind::SoftIrq => "si",
+            CpuTimeKind::IoWait => "wa",
+            CpuTimeKind::Steal => "st",
+        }
+    }
+}
+
+/// Share of elapsed CPU time per category since the previous refresh (%)
+#[derive(Clone, Copy, Default)]
+pub struct CpuTimes {
+    pub user: f32,
+    pub nice: f32,
+    pub system: f32,
+    pub iowait: f32,
+    pub irq: f32,
+    pub softirq: f32,
+    pub steal: f32,
+}
+
+impl CpuTimes {
+    pub fn get(&self, kind: CpuTimeKind) -> f32 {
+        match kind {
+            CpuTimeKind::User => self.user,
+            CpuTimeKind::Nice => self.nice,
+            CpuTimeKind::System => self.system,
+            CpuTimeKind::Irq => self.irq,
+            CpuTimeKind::SoftIrq => self.softirq,
+            CpuTimeKind::IoWait => self.iowait,
+            CpuTimeKind::Steal => self.steal,
+        }
+    }
+
+    /// Percentages from two /proc/stat samples
+    /// (user, nice, system, idle, iowait, irq, softirq, steal; guest is already in user)
+    fn from_delta(prev: &[u64; 8], now: &[u64; 8]) -> Self {
+        let delta: Vec<f32> = now.iter().zip(prev).map(|(n, p)| n.saturating_sub(*p) as f32).collect();
+        let total: f32 = delta.iter().sum();
+        if total == 0.0 {
+            return Self::default();
+        }
+        let pct = |i: usize| delta[i] / total * 100.0;
+        Self {
+            user: pct(0),
+            nice: pct(1),
+            system: pct(2),
+            iowait: pct(4),
+            irq: pct(5),
+            softirq: pct(6),
+            steal: pct(7),
+        }
+    }
+}
 
 #[derive(Clone, Default)]
 pub struct CpuData {
@@ -10,13 +179,127 @@ pub struct CpuData {
     pub logical_cores: usize,
     pub global_usage: f32,
     pub per_core_usage: Vec<f32>,
+    /// Average current frequency across cores
     pub frequency_mhz: u64,
+    pub per_core_frequency: Vec<CoreFrequency>,
+    /// Per-core frequencies come from cpufreq sysfs rather than sysinfo
+    pub has_cpufreq: bool,
+    /// Time breakdown from /proc/stat (None until two samples exist, or off Linux)
+    pub times: Option<CpuTimes>,
+    /// Per-core breakdown, indexed like `per_core_usage`
+    pub per_core_times: Vec<CpuTimes>,
+    /// Indexed like `per_core_usage`; empty when sysfs topology is unavailable
+    pub topology: Vec<CpuTopology>,
+    /// Throttle reasons seen at the latest refresh
+    pub throttling: Vec<ThrottleReason>,
+}
+
+impl CpuData {
+    /// Lowest min / highest max frequency over all cores
+    pub fn frequency_range(&self) -> (u64, u64) {
+        let min = self.per_core_frequency.iter().map(|f| f.min_mhz).filter(|&m| m > 0).min().unwrap_or(0);
+        let max = self.per_core_frequency.iter().map(|f| f.max_mhz).max().unwrap_or(0);
+        (min, max)
+    }
+
+    /// cpuinfo_max_freq when a policy keeps some core below it (power profiles, TLP, battery)
+    pub fn policy_cap(&self) -> Option<u64> {
+        self.per_core_frequency
+            .iter()
+            .find(|f| f.max_mhz > 0 && f.max_mhz < f.hardware_max_mhz)
+            .map(|f| f.hardware_max_mhz)
+    }
+
+    /// Governor shared by every core, or "mixed"
+    pub fn governor(&self) -> String {
+        common(self.per_core_frequency.iter().map(|f| f.governor.as_str()))
+    }
+
+    pub fn driver(&self) -> String {
+        common(self.per_core_frequency.iter().map(|f| f.driver.as_str()))
+    }
+
+    pub fn energy_preference(&self) -> Option<String> {
+        let prefs: Vec<&str> = self.per_core_frequency
+            .iter()
+            .filter_map(|f| f.energy_preference.as_deref())
+            .collect();
+        (!prefs.is_empty()).then(|| common(prefs.into_iter()))
+    }
+
+    pub fn sockets(&self) -> usize {
+        let mut sockets: Vec<u32> = self.topology.iter().map(|t| t.socket).collect();
+        sockets.sort_unstable();
+        sockets.dedup();
+        sockets.len().max(1)
+    }
+
+    pub fn numa_nodes(&self) -> usize {
+        let mut nodes: Vec<u32> = self.topology.iter().filter_map(|t| t.node).collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        nodes.len().max(1)
+    }
+
+    /// Logical CPUs grouped by socket and NUMA node, then by physical core
+    /// (SMT siblings together). Without topology every CPU is its own core.
+    pub fn core_groups(&self) -> Vec<CoreGroup> {
+        let cpus = self.per_core_usage.len();
+        if self.topology.len() != cpus {
+            return vec![CoreGroup {
+                cores: (0..cpus).map(|cpu| vec![cpu]).collect(),
+                ..Default::default()
+            }];
+        }
+
+        let mut order: Vec<usize> = (0..cpus).collect();
+        order.sort_by_key(|&cpu| {
+            let t = &self.topology[cpu];
+            (t.socket, t.node, t.core_id, cpu)
+        });
+
+        let mut groups: Vec<CoreGroup> = Vec::new();
+        for cpu in order {
+            let t = &self.topology[cpu];
+            // Siblings were placed with the lowest-numbered one
+            if t.siblings.iter().any(|&s| s < cpu && s < cpus) {
+                continue;
+            }
+            let core: Vec<usize> = t.siblings.iter().copied().filter(|&s| s < cpus).collect();
+            let core = if core.is_empty() { vec![cpu] } else { core };
+            match groups.last_mut().filter(|g| g.socket == t.socket && g.node == t.node) {
+                Some(group) => group.cores.push(core),
+                None => groups.push(CoreGroup { socket: t.socket, node: t.node, cores: vec![core] }),
+            }
+        }
+        groups
+    }
 }
 
 pub struct CpuCollector {
     system: System,
     pub data: CpuData,
     pub history: History60,
+    /// Usage history per logical core
+    pub per_core_history: Vec<History60>,
+    /// Average frequency in MHz
+    pub frequency_history: History60,
+    /// One history per `CpuTimeKind::ALL` entry
+    pub time_history: [History60; 7],
+    /// Oldest first, capped at MAX_THROTTLE_EVENTS
+    pub throttle_events: Vec<ThrottleEvent>,
+    last_stat: Option<StatSample>,
+    /// Per logical CPU: summed (thermal, power limit) counters from thermal_throttle
+    last_throttle_counts: Vec<Option<(u64, u64)>>,
+    /// Per logical CPU: scaling_max_freq at startup (0 when unknown), so only a cap
+    /// lowered while running counts as throttling
+    startup_max_mhz: Vec<u64>,
+}
+
+/// Raw /proc/stat counters: the aggregate "cpu" line and "cpuN" lines indexed by N
+struct StatSample {
+    total: Option<[u64; 8]>,
+    cores: Vec<Option<[u64; 8]>>,
 }
 
 impl CpuCollector {
@@ -38,12 +321,27 @@ impl CpuCollector {
             global_usage: 0.0,
             per_core_usage: vec![0.0; cpus.len()],
             frequency_mhz: cpus.first().map(|c| c.frequency()).unwrap_or(0),
+            per_core_frequency: Vec::new(),
+            has_cpufreq: false,
+            times: None,
+            per_core_times: Vec::new(),
+            topology: read_topology(cpus.len()),
+            throttling: Vec::new(),
         };
+        let last_throttle_counts = (0..cpus.len()).map(read_throttle_counts).collect();
+        let startup_max_mhz = (0..cpus.len()).map(|cpu| read_core_frequency(cpu).map_or(0, |f| f.max_mhz)).collect();
 
         Ok(Self {
             system,
             data,
             history: History60::default(),
+            per_core_history: Vec::new(),
+            frequency_history: History60::default(),
+            time_history: Default::default(),
+            throttle_events: Vec::new(),
+            last_stat: read_proc_stat(),
+            last_throttle_counts,
+            startup_max_mhz,
         })
     }
 
@@ -59,12 +357,230 @@ impl CpuCollector {
         // Per-core usage
         self.data.per_core_usage = cpus.iter().map(|c| c.cpu_usage()).collect();
 
+        // Per-core frequency: cpufreq sysfs, falling back to sysinfo's reading
+        let sysfs: Vec<Option<CoreFrequency>> = (0..cpus.len()).map(read_core_frequency).collect();
+        self.data.has_cpufreq = sysfs.iter().any(Option::is_some);
+        self.data.per_core_frequency = sysfs
+            .into_iter()
+            .zip(cpus)
+            .map(|(freq, cpu)| freq.unwrap_or_else(|| CoreFrequency {
+                current_mhz: cpu.frequency(),
+                ..Default::default()
+            }))
+            .collect();
+
         // Current frequency
-        self.data.frequency_mhz = cpus.first().map(|c| c.frequency()).unwrap_or(0);
+        let freqs = &self.data.per_core_frequency;
+        self.data.frequency_mhz = if freqs.is_empty() {
+            0
+        } else {
+            freqs.iter().map(|f| f.current_mhz).sum::<u64>() / freqs.len() as u64
+        };
+
+        // Time breakdown from /proc/stat deltas
+        let stat = read_proc_stat();
+        if let (Some(prev), Some(now)) = (&self.last_stat, &stat) {
+            self.data.times = prev.total.zip(now.total).map(|(p, n)| CpuTimes::from_delta(&p, &n));
+            self.data.per_core_times = now.cores
+                .iter()
+                .enumerate()
+                .map(|(i, now)| match (prev.cores.get(i).copied().flatten(), now) {
+                    (Some(p), Some(n)) => CpuTimes::from_delta(&p, n),
+                    _ => CpuTimes::default(),
+                })
+                .collect();
+        }
+        self.last_stat = stat;
+
+        self.detect_throttling();
 
         // Update history
         self.history.push(self.data.global_usage);
+        self.per_core_history.resize_with(self.data.per_core_usage.len(), History60::default);
+        for (history, &usage) in self.per_core_history.iter_mut().zip(&self.data.per_core_usage) {
+            history.push(usage);
+        }
+        self.frequency_history.push(self.data.frequency_mhz as f32);
+        if let Some(times) = self.data.times {
+            for (history, kind) in self.time_history.iter_mut().zip(CpuTimeKind::ALL) {
+                history.push(times.get(kind));
+            }
+        }
 
         Ok(())
     }
+
+    /// Compare thermal_throttle counters with the previous refresh and frequencies
+    /// with their limits, then open or extend one event per active reason
+    fn detect_throttling(&mut self) {
+        let counts: Vec<Option<(u64, u64)>> = (0..self.data.per_core_usage.len()).map(read_throttle_counts).collect();
+        // Indexed like ThrottleReason::ALL
+        let mut affected: Vec<(ThrottleReason, Vec<usize>)> = ThrottleReason::ALL.iter().map(|&r| (r, Vec::new())).collect();
+        for (cpu, now) in counts.iter().enumerate() {
+            if let (Some((thermal, power)), Some(Some((last_thermal, last_power)))) = (now, self.last_throttle_counts.get(cpu)) {
+                if thermal > last_thermal {
+                    affected[0].1.push(cpu);
+                }
+                if power > last_power {
+                    affected[1].1.push(cpu);
+                }
+            }
+            let Some(freq) = self.data.per_core_frequency.get(cpu).filter(|_| self.data.has_cpufreq) else { continue };
+            let busy = self.data.per_core_usage.get(cpu).is_some_and(|&u| u >= 90.0);
+            let floor = freq.base_mhz.unwrap_or(freq.max_mhz * 6 / 10);
+            let low_clock = busy && freq.current_mhz > 0 && freq.current_mhz < floor;
+            // A cap already in place at startup is policy (power profile, TLP), not throttling
+            let lowered = self.startup_max_mhz.get(cpu).is_some_and(|&max| freq.max_mhz > 0 && freq.max_mhz < max);
+            let capped = freq.max_mhz > 0 && freq.max_mhz < freq.hardware_max_mhz;
+            if lowered || (capped && low_clock) {
+                affected[2].1.push(cpu);
+            }
+            if low_clock {
+                affected[3].1.push(cpu);
+            }
+        }
+        self.last_throttle_counts = counts;
+
+        let now = Local::now();
+        let previous = std::mem::take(&mut self.data.throttling);
+        for (reason, cpus) in affected.into_iter().filter(|(_, cpus)| !cpus.is_empty()) {
+            let ongoing = previous.contains(&reason);
+            match self.throttle_events.iter_mut().rev().find(|e| e.reason == reason) {
+                Some(event) if ongoing => event.last_seen = now,
+                _ => self.throttle_events.push(ThrottleEvent { reason, cpus, started: now, last_seen: now }),
+            }
+            self.data.throttling.push(reason);
+        }
+        if self.throttle_events.len() > MAX_THROTTLE_EVENTS {
+            self.throttle_events.drain(..self.throttle_events.len() - MAX_THROTTLE_EVENTS);
+        }
+    }
+}
+
+/// Summed *_throttle_count and *_power_limit_count of one CPU (Intel x86 only)
+fn read_throttle_counts(cpu: usize) -> Option<(u64, u64)> {
+    let entries = fs::read_dir(format!("/sys/devices/system/cpu/cpu{}/thermal_throttle", cpu)).ok()?;
+    let (mut thermal, mut power) = (0, 0);
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let value = || fs::read_to_string(entry.path()).ok().and_then(|s| s.trim().parse::<u64>().ok()).unwrap_or(0);
+        if name.ends_with("_throttle_count") {
+            thermal += value();
+        } else if name.ends_with("_power_limit_count") {
+            power += value();
+        }
+    }
+    Some((thermal, power))
+}
+
+fn read_proc_stat() -> Option<StatSample> {
+    let content = fs::read_to_string("/proc/stat").ok()?;
+    let mut total = None;
+    let mut cores: Vec<Option<[u64; 8]>> = Vec::new();
+    for line in content.lines().filter(|l| l.starts_with("cpu")) {
+        let mut fields = line.split_whitespace();
+        let Some(name) = fields.next() else { continue };
+        let mut values = [0u64; 8];
+        for (slot, value) in values.iter_mut().zip(fields) {
+            *slot = value.parse().unwrap_or(0);
+        }
+        match name["cpu".len()..].parse::<usize>() {
+            Ok(core) => {
+                if cores.len() <= core {
+                    cores.resize(core + 1, None);
+                }
+                cores[core] = Some(values);
+            }
+            Err(_) => total = Some(values),
+        }
+    }
+    Some(StatSample { total, cores })
+}
+
+/// Socket, core and sibling of each logical CPU from sysfs, plus its NUMA node
+fn read_topology(cpus: usize) -> Vec<CpuTopology> {
+    let mut nodes: Vec<Option<u32>> = vec![None; cpus];
+    if let Ok(entries) = fs::read_dir("/sys/devices/system/node") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(node) = name.strip_prefix("node").and_then(|n| n.parse().ok()) else { continue };
+            let list = fs::read_to_string(entry.path().join("cpulist")).unwrap_or_default();
+            for cpu in cpus_in_list(&list, cpus) {
+                nodes[cpu] = Some(node);
+            }
+        }
+    }
+
+    let mut topology: Vec<CpuTopology> = Vec::with_capacity(cpus);
+    let mut found = false;
+    for (cpu, node) in nodes.into_iter().enumerate() {
+        let dir = format!("/sys/devices/system/cpu/cpu{}/topology", cpu);
+        let read = |name: &str| fs::read_to_string(format!("{}/{}", dir, name)).ok();
+        let (Some(socket), Some(core_id)) = (
+            read("physical_package_id").and_then(|s| s.trim().parse().ok()),
+            read("core_id").and_then(|s| s.trim().parse().ok()),
+        ) else {
+            // Offline or hidden CPU: its own core, after the others of the previous CPU's socket
+            let socket = topology.last().map_or(0, |t| t.socket);
+            topology.push(CpuTopology { socket, core_id: u32::MAX, node, siblings: vec![cpu] });
+            continue;
+        };
+        found = true;
+        let siblings = read("thread_siblings_list")
+            .or_else(|| read("core_cpus_list"))
+            .map(|s| cpus_in_list(&s, cpus))
+            .unwrap_or_else(|| vec![cpu]);
+        topology.push(CpuTopology { socket, core_id, node, siblings });
+    }
+    if found {
+        topology
+    } else {
+        Vec::new()
+    }
+}
+
+/// "0-3,8,10-11" → [0, 1, 2, 3, 8, 10, 11]
+/// CPUs below `cpus` named by a sysfs cpulist
+fn cpus_in_list(list: &str, cpus: usize) -> Vec<usize> {
+    let ranges = control::parse_cpu_ranges(list).unwrap_or_default();
+    ranges.into_iter().flat_map(|r| r.cpus().take_while(move |&cpu| cpu < cpus)).collect()
+}
+
+/// Read /sys/devices/system/cpu/cpuN/cpufreq (values there are in kHz)
+fn read_core_frequency(core: usize) -> Option<CoreFrequency> {
+    let dir = format!("/sys/devices/system/cpu/cpu{}/cpufreq", core);
+    let read = |name: &str| fs::read_to_string(format!("{}/{}", dir, name)).ok().map(|s| s.trim().to_string());
+    let khz = |names: &[&str]| names.iter().find_map(|n| read(n)?.parse::<u64>().ok()).map(|k| k / 1000);
+
+    Some(CoreFrequency {
+        current_mhz: khz(&["scaling_cur_freq", "cpuinfo_cur_freq"])?,
+        min_mhz: khz(&["scaling_min_freq", "cpuinfo_min_freq"]).unwrap_or(0),
+        max_mhz: khz(&["scaling_max_freq", "cpuinfo_max_freq"]).unwrap_or(0),
+        hardware_max_mhz: khz(&["cpuinfo_max_freq"]).unwrap_or(0),
+        base_mhz: khz(&["base_frequency"]),
+        governor: read("scaling_governor").unwrap_or_default(),
+        driver: read("scaling_driver").unwrap_or_default(),
+        energy_preference: read("energy_performance_preference"),
+    })
+}
+
+/// The value shared by all items, "mixed" if they differ, "" if there are none
+fn common<'a>(mut values: impl Iterator<Item = &'a str>) -> String {
+    let Some(first) = values.next() else {
+        return String::new();
+    };
+    if values.all(|v| v == first) {
+        first.to_string()
+    } else {
+        "mixed".to_string()
+    }
+}
+
+/// Compact frequency: "3.2G" / "800M"
+pub fn format_frequency(mhz: u64) -> String {
+    if mhz >= 1000 {
+        format!("{:.1}G", mhz as f64 / 1000.0)
+    } else {
+        format!("{}M", mhz)
+    }
 }
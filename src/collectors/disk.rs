@@ -1,9 +1,14 @@
 use anyhow::Result;
+use regex::Regex;
 use sysinfo::Disks;
-use std::time::Instant;
+use std::collections::{HashMap, HashSet};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::fs;
+use std::path::Path;
 
 use super::History60;
+use crate::config::Config;
 
 #[derive(Clone, Default)]
 pub struct DiskData {
@@ -19,164 +24,938 @@ pub struct DiskData {
     pub writes_total: u64,  // total bytes written
 }
 
+/// Raw counters of one /proc/diskstats line
+#[derive(Clone, Copy, Default)]
+struct DiskStats {
+    reads: u64,
+    reads_merged: u64,
+    sectors_read: u64,
+    read_ms: u64,
+    writes: u64,
+    writes_merged: u64,
+    sectors_written: u64,
+    write_ms: u64,
+    in_flight: u64,
+    /// Time the device had at least one request in flight
+    io_ms: u64,
+    /// In-flight requests weighted by the time they spent queued
+    queue_ms: u64,
+}
+
+/// Per-device I/O since the previous refresh, the columns of `iostat -x`
+#[derive(Clone, Default)]
+pub struct DeviceIo {
+    /// Kernel name, e.g. "sda", "nvme0n1", "dm-0"
+    pub name: String,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub read_speed: f64,    // bytes/sec
+    pub write_speed: f64,   // bytes/sec
+    /// Requests merged into a neighbour before reaching the device, per second
+    pub read_merged: f64,
+    pub write_merged: f64,
+    /// Average time per request from queueing to completion, in ms
+    pub read_await: f64,
+    pub write_await: f64,
+    /// Share of the time the device was busy (saturation only for devices serving one request at a time)
+    pub util_percent: f32,
+    /// Average number of requests queued or in service (aqu-sz)
+    pub queue_depth: f64,
+    /// Requests in flight right now
+    pub in_flight: u64,
+}
+
+impl DeviceIo {
+    /// Average latency over reads and writes, in ms
+    pub fn await_ms(&self) -> f64 {
+        let iops = self.read_iops + self.write_iops;
+        if iops > 0.0 {
+            (self.read_await * self.read_iops + self.write_await * self.write_iops) / iops
+        } else {
+            0.0
+        }
+    }
+}
+
+/// What a block device is, from its device-mapper UUID or md level
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DeviceKind {
+    #[default]
+    Disk,
+    Lvm,
+    /// dm-crypt, including LUKS volumes
+    Crypt,
+    Multipath,
+    /// Any other device-mapper target
+    Mapper,
+    /// md software RAID, with its level ("raid1", "raid5", ...)
+    Raid(String),
+    Loop,
+}
+
+impl DeviceKind {
+    pub fn label(&self) -> &str {
+        match self {
+            DeviceKind::Disk => "disk",
+            DeviceKind::Lvm => "LVM",
+            DeviceKind::Crypt => "crypt",
+            DeviceKind::Multipath => "multipath",
+            DeviceKind::Mapper => "dm",
+            DeviceKind::Raid(level) => level,
+            DeviceKind::Loop => "loop",
+        }
+    }
+}
+
+/// A whole block device's place in the device stack
+#[derive(Clone, Default)]
+pub struct BlockDevice {
+    /// Device-mapper name ("vg-root", "luks-3f2a...") or md array name
+    pub alias: Option<String>,
+    pub kind: DeviceKind,
+    /// Devices directly underneath, partitions resolved to their disk
+    pub slaves: Vec<String>,
+    /// Devices built on top of this one or of its partitions
+    pub holders: Vec<String>,
+}
+
+/// One member of an md array: "sdb1[1](F)"
+#[derive(Clone, Default)]
+pub struct RaidMember {
+    pub name: String,
+    pub failed: bool,
+    pub spare: bool,
+}
+
+/// A resync, recovery, reshape or check in progress
+#[derive(Clone, Default)]
+pub struct RaidSync {
+    /// "resync", "recovery", "reshape", "check" or "repair"
+    pub action: String,
+    pub percent: f32,
+    /// Kernel estimate, e.g. "180.2min"; "delayed" / "pending" while waiting for another array
+    pub finish: Option<String>,
+    pub speed: Option<f64>, // bytes/sec
+}
+
+/// One array from /proc/mdstat
+#[derive(Clone, Default)]
+pub struct RaidArray {
+    pub name: String,
+    pub active: bool,
+    /// "raid1", "raid5", ...; empty for an inactive array
+    pub level: String,
+    pub members: Vec<RaidMember>,
+    /// Members the array is built for and members in sync ("[2/1]"); 0 for raid0 / linear
+    pub expected: u32,
+    pub working: u32,
+    /// One character per slot, "U" in sync and "_" missing ("[U_]")
+    pub slots: String,
+    pub sync: Option<RaidSync>,
+}
+
+impl RaidArray {
+    /// Running without full redundancy
+    pub fn degraded(&self) -> bool {
+        self.working < self.expected
+    }
+}
+
+/// One mounted filesystem
+#[derive(Clone, Default)]
+pub struct Filesystem {
+    pub device: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub total_space: u64,
+    pub used_space: u64,
+    /// Space available to unprivileged users (root-reserved blocks excluded)
+    pub free_space: u64,
+    pub usage_percent: f32,
+    /// statvfs did not answer in time (dead network server, hung FUSE daemon);
+    /// sizes are from its last answer
+    pub stale: bool,
+    /// statvfs failed (permission denied, unmounted meanwhile); sizes are from its last answer
+    pub unreadable: bool,
+    /// 0 for filesystems without a fixed inode table (btrfs, some FUSE mounts)
+    pub inodes_total: u64,
+    pub inodes_free: u64,
+    /// Options of this mount ("rw,relatime") and of the filesystem itself ("rw,errors=remount-ro")
+    pub mount_options: String,
+    pub super_options: String,
+    pub read_only: bool,
+    /// Mounted read-write but the filesystem itself went read-only, which is what ext4
+    /// (errors=remount-ro) and btrfs do on errors; also set for a read-only ext4 with errors on record
+    pub remounted_ro: bool,
+    /// ext2/3/4 errors recorded in the superblock since the last fsck
+    pub error_count: u64,
+}
+
+impl Filesystem {
+    /// Share of inodes in use; None when the filesystem has no inode limit
+    pub fn inode_percent(&self) -> Option<f32> {
+        (self.inodes_total > 0).then(|| {
+            self.inodes_total.saturating_sub(self.inodes_free) as f32 / self.inodes_total as f32 * 100.0
+        })
+    }
+}
+
+/// Which filesystems get listed: exclude patterns, overridden by include patterns.
+/// A pattern matches the fs type or the mount point; `*` matches any run of characters.
+pub struct FilesystemFilter {
+    exclude: Vec<Regex>,
+    include: Vec<Regex>,
+}
+
+impl FilesystemFilter {
+    pub fn new(exclude: &[String], include: &[String]) -> Self {
+        Self {
+            exclude: exclude.iter().filter_map(|p| glob_regex(p)).collect(),
+            include: include.iter().filter_map(|p| glob_regex(p)).collect(),
+        }
+    }
+
+    pub fn shows(&self, fs: &Filesystem) -> bool {
+        let matches = |patterns: &[Regex]| {
+            patterns.iter().any(|r| r.is_match(&fs.fs_type) || r.is_match(&fs.mount_point))
+        };
+        !matches(&self.exclude) || matches(&self.include)
+    }
+}
+
+const SYS_BLOCK: &str = "/sys/block";
+const SYS_CLASS_BLOCK: &str = "/sys/class/block";
+const MDSTAT: &str = "/proc/mdstat";
+
+/// How long the mounts get, together, to answer statvfs
+const STATVFS_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// statvfs worker threads; each hung mount ties one up until it answers
+const STATVFS_WORKERS: usize = 4;
+
+/// What asking a mount for its usage gave this refresh
+enum FsUsage {
+    /// sysinfo already filled in the sizes
+    Sized,
+    Answered(FsStats),
+    Failed,
+    TimedOut,
+}
+
+/// Long-lived threads running statvfs, so a mount that hangs (dead NFS server,
+/// stuck FUSE daemon) never blocks the refresh
+struct StatvfsWorkers {
+    jobs: mpsc::Sender<String>,
+    results: mpsc::Receiver<(String, Option<FsStats>)>,
+    /// Mounts asked and not answered yet; they are not asked again until they answer
+    pending: HashSet<String>,
+}
+
+impl StatvfsWorkers {
+    fn new() -> Self {
+        let (jobs, job_rx) = mpsc::channel::<String>();
+        let (result_tx, results) = mpsc::channel();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        for _ in 0..STATVFS_WORKERS {
+            let jobs = Arc::clone(&job_rx);
+            let results = result_tx.clone();
+            std::thread::spawn(move || loop {
+                // Ends once the collector (and with it the job sender) is dropped
+                let Ok(mount) = jobs.lock().map_err(drop).and_then(|rx| rx.recv().map_err(drop)) else {
+                    return;
+                };
+                let stats = statvfs(&mount);
+                if results.send((mount, stats)).is_err() {
+                    return;
+                }
+            });
+        }
+        Self { jobs, results, pending: HashSet::new() }
+    }
+
+    /// statvfs every mount at once and wait for them against a single deadline.
+    /// Mounts missing from the result did not answer in time.
+    fn query<'a>(&mut self, mounts: impl Iterator<Item = &'a str>) -> HashMap<String, Option<FsStats>> {
+        let mut waiting = HashSet::new();
+        for mount in mounts {
+            waiting.insert(mount.to_string());
+            if self.pending.insert(mount.to_string()) && self.jobs.send(mount.to_string()).is_err() {
+                self.pending.remove(mount);
+            }
+        }
+        let deadline = Instant::now() + STATVFS_TIMEOUT;
+        let mut answers = HashMap::new();
+        // Late answers from earlier refreshes are picked up here too
+        while waiting.iter().any(|m| self.pending.contains(m)) {
+            let Ok((mount, stats)) = self.results.recv_timeout(deadline.saturating_duration_since(Instant::now())) else {
+                break;
+            };
+            self.pending.remove(&mount);
+            answers.insert(mount, stats);
+        }
+        answers
+    }
+}
+
 pub struct DiskCollector {
     disks: Disks,
     pub data: DiskData,
+    /// Mounted filesystems passing the filter, in mount order
+    pub filesystems: Vec<Filesystem>,
+    /// How many the filter hid
+    pub hidden: usize,
+    /// Whole block devices that have seen I/O since boot
+    pub devices: Vec<DeviceIo>,
+    /// Device stack (device-mapper, md, disks) by kernel name ("dm-0", "md127", "sda")
+    pub block_devices: HashMap<String, BlockDevice>,
+    pub raid: Vec<RaidArray>,
     pub read_history: History60,
     pub write_history: History60,
+    filter: FilesystemFilter,
+    /// Mount point chosen for the summary panel (None: "/" or the largest)
+    preferred_mount: Option<String>,
+    /// statvfs for every mount, any of which may hang
+    statvfs: StatvfsWorkers,
     last_update: Instant,
+    /// Kernel name of the device behind the summary filesystem
     device_name: String,
-    last_sectors_read: u64,
-    last_sectors_written: u64,
+    last_stats: HashMap<String, DiskStats>,
 }
 
 impl DiskCollector {
-    pub fn new() -> Result<Self> {
-        let disks = Disks::new_with_refreshed_list();
+    pub fn new(config: &Config) -> Result<Self> {
+        let mut collector = Self {
+            disks: Disks::new(),
+            data: DiskData::default(),
+            filesystems: Vec::new(),
+            hidden: 0,
+            devices: Vec::new(),
+            block_devices: read_block_devices(),
+            raid: read_mdstat(),
+            read_history: History60::default(),
+            write_history: History60::default(),
+            filter: FilesystemFilter::new(&config.filesystem_exclude, &config.filesystem_include),
+            preferred_mount: config.disk_mount.clone(),
+            statvfs: StatvfsWorkers::new(),
+            last_update: Instant::now(),
+            device_name: String::new(),
+            last_stats: read_disk_stats(),
+        };
+        collector.refresh_filesystems();
+        collector.select_primary();
+        Ok(collector)
+    }
 
-        // Find primary disk (root mount or largest)
-        let primary = disks
-            .iter()
-            .find(|d| d.mount_point().to_string_lossy() == "/")
-            .or_else(|| disks.iter().max_by_key(|d| d.total_space()));
-
-        let (data, device_name) = if let Some(disk) = primary {
-            let total = disk.total_space();
-            let free = disk.available_space();
-            let used = total.saturating_sub(free);
-
-            // Extract device name (e.g., "sda" from "/dev/sda1")
-            let dev_name = disk.name().to_string_lossy().to_string();
-            let device = extract_device_name(&dev_name);
-
-            (DiskData {
-                name: dev_name,
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                total_space: total,
-                used_space: used,
-                free_space: free,
-                usage_percent: if total > 0 {
-                    (used as f32 / total as f32) * 100.0
-                } else {
-                    0.0
-                },
-                read_speed: 0.0,
-                write_speed: 0.0,
-                reads_total: 0,
-                writes_total: 0,
-            }, device)
-        } else {
-            (DiskData::default(), String::new())
+    /// Show `mount_point` in the summary panel
+    pub fn select(&mut self, mount_point: &str) {
+        self.preferred_mount = Some(mount_point.to_string());
+        self.select_primary();
+    }
+
+    /// Point the summary at the chosen mount, else "/", else the largest filesystem
+    fn select_primary(&mut self) {
+        let primary = self.preferred_mount
+            .as_ref()
+            .and_then(|m| self.filesystems.iter().find(|f| &f.mount_point == m))
+            .or_else(|| self.filesystems.iter().find(|f| f.mount_point == "/"))
+            .or_else(|| self.filesystems.iter().max_by_key(|f| f.total_space));
+
+        let Some(fs) = primary else {
+            self.data = DiskData::default();
+            self.device_name.clear();
+            return;
+        };
+        self.data = DiskData {
+            name: fs.device.clone(),
+            mount_point: fs.mount_point.clone(),
+            ..Default::default()
         };
+        self.update_summary_space();
 
-        // Get initial disk stats
-        let (sectors_read, sectors_written) = read_disk_stats(&device_name).unwrap_or((0, 0));
+        // Whole device behind the filesystem (e.g., "sda" from "/dev/sda1", "dm-0" from "/dev/mapper/vg-root")
+        self.device_name = resolve_block_device(&self.data.name, &self.data.mount_point)
+            .unwrap_or_else(|| extract_device_name(&self.data.name));
+        self.update_summary_io();
+    }
 
-        Ok(Self {
-            disks,
-            data,
-            read_history: History60::default(),
-            write_history: History60::default(),
-            last_update: Instant::now(),
-            device_name,
-            last_sectors_read: sectors_read,
-            last_sectors_written: sectors_written,
-        })
+    /// Physical disks at the bottom of `name`'s device stack (just `name` for a plain disk)
+    pub fn backing_disks(&self, name: &str) -> Vec<String> {
+        let mut disks = Vec::new();
+        let mut pending = vec![name.to_string()];
+        let mut seen = HashSet::new();
+        while let Some(device) = pending.pop() {
+            if !seen.insert(device.clone()) {
+                continue;
+            }
+            match self.block_devices.get(&device) {
+                Some(block) if !block.slaves.is_empty() => pending.extend(block.slaves.iter().cloned()),
+                _ => disks.push(device),
+            }
+        }
+        disks.sort();
+        disks
     }
 
-    pub fn collect(&mut self) -> Result<()> {
-        self.disks.refresh();
+    /// "LVM on sda, sdb" for stacked devices, "under md0" for RAID members, None for a plain disk
+    pub fn describe_stack(&self, name: &str) -> Option<String> {
+        let block = self.block_devices.get(name)?;
+        if !block.slaves.is_empty() {
+            return Some(format!("{} on {}", block.kind.label(), self.backing_disks(name).join(", ")));
+        }
+        if !block.holders.is_empty() {
+            let holders: Vec<&str> = block.holders
+                .iter()
+                .map(|h| self.block_devices.get(h).and_then(|b| b.alias.as_deref()).unwrap_or(h))
+                .collect();
+            return Some(format!("under {}", holders.join(", ")));
+        }
+        None
+    }
+
+    /// The filesystem shown in the summary panel
+    pub fn summary_filesystem(&self) -> Option<&Filesystem> {
+        self.filesystems.iter().find(|f| f.mount_point == self.data.mount_point)
+    }
+
+    /// Kernel name of the device behind the summary filesystem
+    pub fn summary_device(&self) -> &str {
+        &self.device_name
+    }
 
+    /// I/O of the device behind the summary filesystem
+    pub fn summary_io(&self) -> Option<&DeviceIo> {
+        self.devices.iter().find(|d| d.name == self.device_name)
+    }
+
+    fn update_summary_io(&mut self) {
+        let io = self.summary_io().map(|io| (io.read_speed, io.write_speed)).unwrap_or_default();
+        (self.data.read_speed, self.data.write_speed) = io;
+        if let Some(stats) = self.last_stats.get(&self.device_name) {
+            self.data.reads_total = stats.sectors_read * 512;
+            self.data.writes_total = stats.sectors_written * 512;
+        }
+    }
+
+    fn update_summary_space(&mut self) {
+        if let Some(fs) = self.filesystems.iter().find(|f| f.mount_point == self.data.mount_point) {
+            self.data.total_space = fs.total_space;
+            self.data.used_space = fs.used_space;
+            self.data.free_space = fs.free_space;
+            self.data.usage_percent = fs.usage_percent;
+        }
+    }
+
+    /// Re-read the mount table and the usage of every listed filesystem
+    fn refresh_filesystems(&mut self) {
+        let mut mounted = match read_mounts() {
+            Some(mounts) => mounts,
+            None => self.sysinfo_filesystems(),
+        };
+        let total = mounted.len();
+        mounted.retain(|fs| self.filter.shows(fs));
+        self.hidden = total - mounted.len();
+
+        // Any mount can block (network, FUSE, 9p, virtiofs), so every one goes through the workers
+        let mut answers = self.statvfs.query(
+            mounted.iter().filter(|fs| fs.total_space == 0).map(|fs| fs.mount_point.as_str()),
+        );
+        for fs in &mut mounted {
+            let usage = if fs.total_space > 0 {
+                FsUsage::Sized
+            } else {
+                match answers.remove(&fs.mount_point) {
+                    Some(Some(stats)) => FsUsage::Answered(stats),
+                    Some(None) => FsUsage::Failed,
+                    None => FsUsage::TimedOut,
+                }
+            };
+            match usage {
+                FsUsage::Sized => {}
+                FsUsage::Answered(stats) => {
+                    fs.total_space = stats.total;
+                    fs.used_space = stats.used;
+                    fs.free_space = stats.available;
+                    fs.inodes_total = stats.inodes_total;
+                    fs.inodes_free = stats.inodes_free;
+                }
+                FsUsage::Failed | FsUsage::TimedOut => {
+                    fs.stale = matches!(usage, FsUsage::TimedOut);
+                    fs.unreadable = matches!(usage, FsUsage::Failed);
+                    if let Some(last) = self.filesystems.iter().find(|f| f.mount_point == fs.mount_point) {
+                        fs.total_space = last.total_space;
+                        fs.used_space = last.used_space;
+                        fs.free_space = last.free_space;
+                        fs.inodes_total = last.inodes_total;
+                        fs.inodes_free = last.inodes_free;
+                    }
+                }
+            }
+            if fs.fs_type.starts_with("ext") {
+                fs.error_count = read_ext4_errors(&fs.device);
+            }
+            fs.remounted_ro |= fs.read_only && fs.error_count > 0;
+            // Like df: used / (used + available), so a disk is full once only root could write
+            let usable = fs.used_space + fs.free_space;
+            fs.usage_percent = if usable > 0 { fs.used_space as f32 / usable as f32 * 100.0 } else { 0.0 };
+        }
+        self.filesystems = mounted;
+    }
+
+    /// Off Linux there is no /proc/self/mountinfo; sysinfo lists the real disks with sizes
+    fn sysinfo_filesystems(&mut self) -> Vec<Filesystem> {
+        self.disks.refresh_list();
+        self.disks
+            .iter()
+            .map(|d| {
+                let total = d.total_space();
+                let free = d.available_space();
+                Filesystem {
+                    device: d.name().to_string_lossy().to_string(),
+                    mount_point: d.mount_point().to_string_lossy().to_string(),
+                    fs_type: d.file_system().to_string_lossy().to_string(),
+                    total_space: total,
+                    used_space: total.saturating_sub(free),
+                    free_space: free,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    pub fn collect(&mut self) -> Result<()> {
         let elapsed = self.last_update.elapsed().as_secs_f64();
         if elapsed < 0.1 {
             return Ok(());
         }
 
-        // Update disk usage
-        if let Some(disk) = self.disks
-            .iter()
-            .find(|d| d.mount_point().to_string_lossy() == self.data.mount_point)
-        {
-            let total = disk.total_space();
-            let free = disk.available_space();
-            let used = total.saturating_sub(free);
-
-            self.data.total_space = total;
-            self.data.used_space = used;
-            self.data.free_space = free;
-            self.data.usage_percent = if total > 0 {
-                (used as f32 / total as f32) * 100.0
-            } else {
-                0.0
-            };
+        // Update disk usage; re-pick the summary filesystem if it was unmounted
+        self.refresh_filesystems();
+        if !self.filesystems.iter().any(|f| f.mount_point == self.data.mount_point) {
+            self.select_primary();
         }
+        self.update_summary_space();
+
+        self.block_devices = read_block_devices();
+        self.raid = read_mdstat();
 
         // Read I/O stats from /proc/diskstats
-        if !self.device_name.is_empty() {
-            if let Some((sectors_read, sectors_written)) = read_disk_stats(&self.device_name) {
-                // Calculate bytes diff (1 sector = 512 bytes)
-                let bytes_read = (sectors_read.saturating_sub(self.last_sectors_read)) * 512;
-                let bytes_written = (sectors_written.saturating_sub(self.last_sectors_written)) * 512;
-
-                // Calculate speed (bytes per second)
-                self.data.read_speed = bytes_read as f64 / elapsed;
-                self.data.write_speed = bytes_written as f64 / elapsed;
-
-                // Update totals
-                self.data.reads_total = sectors_read * 512;
-                self.data.writes_total = sectors_written * 512;
-
-                // Save for next iteration
-                self.last_sectors_read = sectors_read;
-                self.last_sectors_written = sectors_written;
-            }
-        }
+        let stats = read_disk_stats();
+        self.devices = stats
+            .iter()
+            .filter(|(_, now)| now.reads + now.writes > 0)
+            .map(|(name, now)| {
+                let last = self.last_stats.get(name).copied().unwrap_or(*now);
+                device_io(name, &last, now, elapsed)
+            })
+            .collect();
+        self.devices.sort_by(|a, b| a.name.cmp(&b.name));
+        self.last_stats = stats;
+        self.update_summary_io();
 
         self.last_update = Instant::now();
 
-        // Update history (in MB/s)
-        self.read_history.push((self.data.read_speed / 1_000_000.0) as f32);
-        self.write_history.push((self.data.write_speed / 1_000_000.0) as f32);
+        // Update history (in KB/s, so light I/O still shows)
+        self.read_history.push((self.data.read_speed / 1_000.0) as f32);
+        self.write_history.push((self.data.write_speed / 1_000.0) as f32);
 
         Ok(())
     }
 }
 
-/// Extract base device name (e.g., "sda" from "/dev/sda1" or "sda1")
+/// /proc/self/mountinfo, one entry per mount point (the last mount over a path wins):
+///
+/// ```text
+/// 36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue
+/// ```
+fn read_mounts() -> Option<Vec<Filesystem>> {
+    let content = fs::read_to_string("/proc/self/mountinfo").ok()?;
+    let mut mounts: Vec<Filesystem> = Vec::new();
+    for line in content.lines() {
+        // Spaces inside fields are escaped, so " - " only ends the optional fields
+        let Some((mount, filesystem)) = line.split_once(" - ") else { continue };
+        let mount: Vec<&str> = mount.split_whitespace().collect();
+        let mut filesystem = filesystem.split_whitespace();
+        let (Some(mount_point), Some(options)) = (mount.get(4), mount.get(5)) else { continue };
+        let (Some(fs_type), Some(device)) = (filesystem.next(), filesystem.next()) else { continue };
+        let super_options = filesystem.next().unwrap_or_default();
+
+        let has = |options: &str, option: &str| options.split(',').any(|o| o == option);
+        let mount_point = unescape_mount_field(mount_point);
+        mounts.retain(|m| m.mount_point != mount_point);
+        mounts.push(Filesystem {
+            device: unescape_mount_field(device),
+            mount_point,
+            fs_type: fs_type.to_string(),
+            read_only: has(options, "ro") || has(super_options, "ro"),
+            remounted_ro: has(options, "rw") && has(super_options, "ro"),
+            mount_options: options.to_string(),
+            super_options: super_options.to_string(),
+            ..Default::default()
+        });
+    }
+    Some(mounts)
+}
+
+/// /sys/fs/ext4/<partition>/errors_count (ext2 and ext3 are served by the ext4 driver too)
+fn read_ext4_errors(device: &str) -> u64 {
+    let Some(name) = fs::canonicalize(device).ok().and_then(|p| p.file_name().map(|f| f.to_string_lossy().to_string())) else {
+        return 0;
+    };
+    read_trimmed(&Path::new("/sys/fs/ext4").join(name).join("errors_count"))
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(0)
+}
+
+/// The mount table escapes space, tab, newline and backslash as octal ("\040")
+fn unescape_mount_field(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut rest = field;
+    while let Some(pos) = rest.find('\\') {
+        result.push_str(&rest[..pos]);
+        let code = rest.get(pos + 1..pos + 4).and_then(|o| u8::from_str_radix(o, 8).ok());
+        match code {
+            Some(byte) => {
+                result.push(byte as char);
+                rest = &rest[pos + 4..];
+            }
+            None => {
+                result.push('\\');
+                rest = &rest[pos + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// "tmp*" → ^tmp.*$
+fn glob_regex(pattern: &str) -> Option<Regex> {
+    let glob = pattern.split('*').map(regex::escape).collect::<Vec<_>>().join(".*");
+    Regex::new(&format!("^{}$", glob)).ok()
+}
+
+/// Sizes from statvfs(3), in bytes, and inode counts
+#[derive(Clone, Copy, Default)]
+struct FsStats {
+    total: u64,
+    used: u64,
+    available: u64,
+    inodes_total: u64,
+    inodes_free: u64,
+}
+
+fn statvfs(path: &str) -> Option<FsStats> {
+    #[cfg(unix)]
+    {
+        let path = std::ffi::CString::new(path).ok()?;
+        // SAFETY: `stat` is a plain C struct that statvfs(3) fills in; `path` is NUL-terminated
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+            return None;
+        }
+        // Field widths differ between platforms
+        let block = stat.f_frsize as u64;
+        Some(FsStats {
+            total: stat.f_blocks as u64 * block,
+            used: (stat.f_blocks as u64).saturating_sub(stat.f_bfree as u64) * block,
+            available: stat.f_bavail as u64 * block,
+            inodes_total: stat.f_files as u64,
+            inodes_free: stat.f_ffree as u64,
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Kernel name of the whole device behind a mount ("/dev/mapper/vg-root" → "dm-0",
+/// "/dev/mmcblk0p2" → "mmcblk0"). Follows the /dev symlinks, then falls back to the
+/// mount point's device number for sources such as "/dev/root".
+fn resolve_block_device(source: &str, mount_point: &str) -> Option<String> {
+    let from_source = || {
+        if !source.starts_with("/dev/") {
+            return None;
+        }
+        let path = fs::canonicalize(source).ok()?;
+        // sysfs spells "cciss/c0d0" as "cciss!c0d0"
+        let name = path.strip_prefix("/dev").ok()?.to_string_lossy().replace('/', "!");
+        fs::canonicalize(Path::new(SYS_CLASS_BLOCK).join(name)).ok()
+    };
+    let sys = from_source().or_else(|| sysfs_by_dev_number(mount_point))?;
+    // A partition's sysfs directory sits inside its disk's
+    let whole = if sys.join("partition").exists() { sys.parent()? } else { &sys };
+    Some(whole.file_name()?.to_string_lossy().replace('!', "/"))
+}
+
+/// /sys/dev/block/MAJ:MIN of the device a path lives on
+fn sysfs_by_dev_number(path: &str) -> Option<std::path::PathBuf> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let dev = fs::metadata(path).ok()?.dev();
+        // Linux dev_t: minor in bits 0-7 and 20-39, major in bits 8-19 and 40-63
+        let major = ((dev >> 32) & 0xffff_f000) | ((dev >> 8) & 0xfff);
+        let minor = ((dev >> 12) & 0xffff_ff00) | (dev & 0xff);
+        fs::canonicalize(format!("/sys/dev/block/{}:{}", major, minor)).ok()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Guess the base device name (e.g., "sda" from "/dev/sda1") when sysfs cannot tell
 fn extract_device_name(name: &str) -> String {
     let name = name.trim_start_matches("/dev/");
-    // Remove partition number (e.g., "sda1" -> "sda", "nvme0n1p1" -> "nvme0n1")
-    if name.starts_with("nvme") {
-        // NVMe format: nvme0n1p1 -> nvme0n1
-        if let Some(pos) = name.rfind('p') {
-            if name[pos + 1..].chars().all(|c| c.is_ascii_digit()) {
-                return name[..pos].to_string();
+    // Device-mapper and md devices are whole devices despite the trailing digits
+    if name.starts_with("dm-") || name.starts_with("md") || name.starts_with("mapper/") {
+        return name.to_string();
+    }
+    // "nvme0n1p1" -> "nvme0n1", "mmcblk0p2" -> "mmcblk0", "loop0p1" -> "loop0"
+    if let Some(pos) = name.rfind('p') {
+        let (disk, partition) = (&name[..pos], &name[pos + 1..]);
+        if disk.ends_with(|c: char| c.is_ascii_digit())
+            && !partition.is_empty()
+            && partition.chars().all(|c| c.is_ascii_digit())
+        {
+            return disk.to_string();
+        }
+    }
+    if name.starts_with("nvme") || name.starts_with("mmcblk") || name.starts_with("loop") {
+        return name.to_string();
+    }
+    // Standard format: sda1 -> sda
+    name.trim_end_matches(|c: char| c.is_ascii_digit()).to_string()
+}
+
+/// Every whole device in /sys/block with what sits under and over it
+fn read_block_devices() -> HashMap<String, BlockDevice> {
+    let Ok(entries) = fs::read_dir(SYS_BLOCK) else {
+        return HashMap::new();
+    };
+    let mut devices = HashMap::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let name = entry.file_name().to_string_lossy().replace('!', "/");
+
+        let (kind, alias) = if dir.join("dm").is_dir() {
+            let uuid = read_trimmed(&dir.join("dm/uuid")).unwrap_or_default();
+            let kind = if uuid.starts_with("LVM-") {
+                DeviceKind::Lvm
+            } else if uuid.starts_with("CRYPT-") {
+                DeviceKind::Crypt
+            } else if uuid.starts_with("mpath-") {
+                DeviceKind::Multipath
+            } else {
+                DeviceKind::Mapper
+            };
+            (kind, read_trimmed(&dir.join("dm/name")))
+        } else if dir.join("md").is_dir() {
+            let level = read_trimmed(&dir.join("md/level")).unwrap_or_default();
+            // Named arrays ("/dev/md/home") appear as md127 with a "home" link in /dev/md
+            let alias = list_dir(Path::new("/dev/md")).into_iter().find(|link| {
+                fs::canonicalize(Path::new("/dev/md").join(link))
+                    .is_ok_and(|target| target.file_name().is_some_and(|f| f.to_string_lossy() == name))
+            });
+            (DeviceKind::Raid(level), alias)
+        } else if name.starts_with("loop") {
+            (DeviceKind::Loop, None)
+        } else {
+            (DeviceKind::Disk, None)
+        };
+
+        let slaves = list_dir(&dir.join("slaves"))
+            .into_iter()
+            .map(|slave| whole_device_of(&slave))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        // Holders of the disk itself and of each of its partitions
+        let mut holders = list_dir(&dir.join("holders"));
+        for child in list_dir(&dir) {
+            if dir.join(&child).join("partition").exists() {
+                holders.extend(list_dir(&dir.join(&child).join("holders")));
             }
         }
-        name.to_string()
-    } else {
-        // Standard format: sda1 -> sda
-        name.trim_end_matches(|c: char| c.is_ascii_digit()).to_string()
+        holders.sort();
+        holders.dedup();
+
+        devices.insert(name, BlockDevice { alias, kind, slaves, holders });
     }
+    devices
 }
 
-/// Read disk stats from /proc/diskstats
-/// Returns (sectors_read, sectors_written) for the given device
-fn read_disk_stats(device: &str) -> Option<(u64, u64)> {
-    let content = fs::read_to_string("/proc/diskstats").ok()?;
+/// "sda2" → "sda"; whole devices are returned unchanged
+fn whole_device_of(name: &str) -> String {
+    fs::canonicalize(Path::new(SYS_CLASS_BLOCK).join(name))
+        .ok()
+        .filter(|sys| sys.join("partition").exists())
+        .and_then(|sys| sys.parent().and_then(|p| p.file_name()).map(|f| f.to_string_lossy().to_string()))
+        .unwrap_or_else(|| name.to_string())
+        .replace('!', "/")
+}
+
+fn list_dir(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect()
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Parse /proc/mdstat:
+///
+/// ```text
+/// md0 : active raid1 sdb1[1] sda1[0](F)
+///       1953382464 blocks super 1.2 [2/1] [U_]
+///       [==>..................]  recovery = 12.6% (246720000/1953382464) finish=180.2min speed=157827K/sec
+/// ```
+fn read_mdstat() -> Vec<RaidArray> {
+    let Ok(content) = fs::read_to_string(MDSTAT) else {
+        return Vec::new();
+    };
+    let mut arrays: Vec<RaidArray> = Vec::new();
+    for line in content.lines() {
+        if let Some((name, rest)) = line.split_once(" : ").filter(|(n, _)| !n.starts_with(' ') && n.starts_with("md")) {
+            let mut array = RaidArray { name: name.trim().to_string(), ..Default::default() };
+            for token in rest.split_whitespace() {
+                if let Some((member, flags)) = token.split_once('[') {
+                    array.members.push(RaidMember {
+                        name: member.to_string(),
+                        failed: flags.contains("(F)"),
+                        spare: flags.contains("(S)"),
+                    });
+                } else if token == "active" {
+                    array.active = true;
+                } else if token != "inactive" && !token.starts_with('(') && array.level.is_empty() {
+                    array.level = token.to_string();
+                }
+            }
+            arrays.push(array);
+            continue;
+        }
+        let Some(array) = arrays.last_mut() else { continue };
+        let line = line.trim();
+        if line.contains(" blocks") {
+            // "... [2/1] [U_]"
+            for token in line.split_whitespace() {
+                let Some(inner) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) else { continue };
+                if let Some((expected, working)) = inner.split_once('/') {
+                    array.expected = expected.parse().unwrap_or(0);
+                    array.working = working.parse().unwrap_or(0);
+                } else if inner.chars().all(|c| c == 'U' || c == '_') {
+                    array.slots = inner.to_string();
+                }
+            }
+        } else if let Some(sync) = parse_sync_line(line) {
+            array.sync = Some(sync);
+        }
+    }
+    arrays
+}
+
+/// "[==>...]  recovery = 12.6% (...) finish=180.2min speed=157827K/sec" or "resync=DELAYED"
+fn parse_sync_line(line: &str) -> Option<RaidSync> {
+    const ACTIONS: [&str; 5] = ["resync", "recovery", "reshape", "check", "repair"];
+    // Skip the progress bar
+    let line = match line.strip_prefix('[') {
+        Some(bar) => bar.split_once(']')?.1,
+        None => line,
+    };
+    let (action, rest) = line.split_once('=')?;
+    let action = action.trim();
+    if !ACTIONS.contains(&action) {
+        return None;
+    }
+    let rest = rest.trim();
+    let mut sync = RaidSync { action: action.to_string(), ..Default::default() };
+    if let Some(waiting) = ["DELAYED", "PENDING"].iter().find(|w| rest.starts_with(**w)) {
+        sync.finish = Some(waiting.to_lowercase());
+        return Some(sync);
+    }
+    for token in rest.split_whitespace() {
+        if let Some(percent) = token.strip_suffix('%') {
+            sync.percent = percent.parse().unwrap_or(0.0);
+        } else if let Some(finish) = token.strip_prefix("finish=") {
+            sync.finish = Some(finish.to_string());
+        } else if let Some(speed) = token.strip_prefix("speed=") {
+            sync.speed = speed.strip_suffix("K/sec").and_then(|k| k.parse::<f64>().ok()).map(|k| k * 1024.0);
+        }
+    }
+    Some(sync)
+}
+
+/// Read disk stats from /proc/diskstats for every whole device (partitions share
+/// their disk's queue, so their latency and utilization say nothing new)
+fn read_disk_stats() -> HashMap<String, DiskStats> {
+    let content = fs::read_to_string("/proc/diskstats").unwrap_or_default();
+    let mut stats = HashMap::new();
 
     for line in content.lines() {
         let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 11 && parts[2] == device {
-            // Field 6 (index 5): sectors read
-            // Field 10 (index 9): sectors written
-            let sectors_read = parts[5].parse().ok()?;
-            let sectors_written = parts[9].parse().ok()?;
-            return Some((sectors_read, sectors_written));
+        if parts.len() < 14 {
+            continue;
+        }
+        let name = parts[2];
+        // sysfs spells "cciss/c0d0" as "cciss!c0d0"
+        if !Path::new(SYS_BLOCK).join(name.replace('/', "!")).exists() {
+            continue;
         }
+        let field = |i: usize| parts[i].parse::<u64>().unwrap_or(0);
+        stats.insert(
+            name.to_string(),
+            DiskStats {
+                reads: field(3),
+                reads_merged: field(4),
+                sectors_read: field(5),
+                read_ms: field(6),
+                writes: field(7),
+                writes_merged: field(8),
+                sectors_written: field(9),
+                write_ms: field(10),
+                in_flight: field(11),
+                io_ms: field(12),
+                queue_ms: field(13),
+            },
+        );
     }
 
-    None
+    stats
+}
+
+/// Rates between two samples, computed the way iostat does
+fn device_io(name: &str, last: &DiskStats, now: &DiskStats, elapsed: f64) -> DeviceIo {
+    let delta = |now: u64, last: u64| now.saturating_sub(last) as f64;
+    let reads = delta(now.reads, last.reads);
+    let writes = delta(now.writes, last.writes);
+    let per_request = |ms: f64, requests: f64| if requests > 0.0 { ms / requests } else { 0.0 };
+    let elapsed_ms = elapsed * 1000.0;
+
+    DeviceIo {
+        name: name.to_string(),
+        read_iops: reads / elapsed,
+        write_iops: writes / elapsed,
+        // 1 sector = 512 bytes, whatever the device's real sector size
+        read_speed: delta(now.sectors_read, last.sectors_read) * 512.0 / elapsed,
+        write_speed: delta(now.sectors_written, last.sectors_written) * 512.0 / elapsed,
+        read_merged: delta(now.reads_merged, last.reads_merged) / elapsed,
+        write_merged: delta(now.writes_merged, last.writes_merged) / elapsed,
+        read_await: per_request(delta(now.read_ms, last.read_ms), reads),
+        write_await: per_request(delta(now.write_ms, last.write_ms), writes),
+        util_percent: (delta(now.io_ms, last.io_ms) / elapsed_ms * 100.0).min(100.0) as f32,
+        queue_depth: delta(now.queue_ms, last.queue_ms) / elapsed_ms,
+        in_flight: now.in_flight,
+    }
 }
 
 /// Format disk space
@@ -35,41 +35,38 @@ pub struct GpuCollector {
 }
 
 impl GpuCollector {
-    pub fn new() -> Result<Self> {
+    pub fn new(enabled: bool) -> Result<Self> {
         #[cfg(feature = "gpu")]
-        {
-            match nvml_wrapper::Nvml::init() {
-                Ok(nvml) => {
-                    // Leak nvml to get 'static lifetime (acceptable for single instance)
-                    let nvml = Box::leak(Box::new(nvml));
-
-                    match nvml.device_by_index(0) {
-                        Ok(device) => {
-                            let name = device.name().unwrap_or_default();
-                            let driver = nvml.sys_driver_version().unwrap_or_default();
-
-                            let device = Box::leak(Box::new(device));
-
-                            return Ok(Self {
-                                nvml: Some(unsafe { std::ptr::read(nvml) }),
-                                device: Some(unsafe { std::ptr::read(device) }),
-                                data: GpuData {
-                                    available: true,
-                                    name,
-                                    driver_version: driver,
-                                    ..Default::default()
-                                },
-                                history: History60::default(),
-                            });
-                        }
-                        Err(_) => {}
-                    }
+        if enabled {
+            if let Ok(nvml) = nvml_wrapper::Nvml::init() {
+                // Leak nvml to get 'static lifetime (acceptable for single instance)
+                let nvml = Box::leak(Box::new(nvml));
+
+                if let Ok(device) = nvml.device_by_index(0) {
+                    let name = device.name().unwrap_or_default();
+                    let driver = nvml.sys_driver_version().unwrap_or_default();
+
+                    let device = Box::leak(Box::new(device));
+
+                    return Ok(Self {
+                        nvml: Some(unsafe { std::ptr::read(nvml) }),
+                        device: Some(unsafe { std::ptr::read(device) }),
+                        data: GpuData {
+                            available: true,
+                            name,
+                            driver_version: driver,
+                            ..Default::default()
+                        },
+                        history: History60::default(),
+                    });
                 }
-                Err(_) => {}
             }
         }
 
-        // No GPU available
+        #[cfg(not(feature = "gpu"))]
+        let _ = enabled;
+
+        // No GPU available (or disabled)
         Ok(Self {
             #[cfg(feature = "gpu")]
             nvml: None,
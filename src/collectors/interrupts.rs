@@ -0,0 +1,278 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::time::Instant;
+
+use super::History60;
+
+/// One line of /proc/interrupts with its rates since the previous refresh
+#[derive(Clone, Default)]
+pub struct IrqLine {
+    /// "45", or a per-CPU source such as "LOC" / "NMI"
+    pub irq: String,
+    /// Interrupt controller and trigger, e.g. "IR-PCI-MSIX-0000:00:1f.6 0-edge"
+    pub chip: String,
+    /// Devices (actions) on the line, e.g. "eth0-TxRx-0"; a description for named lines
+    pub device: String,
+    pub rate: f64,
+    /// Interrupts per second on each CPU, indexed like `InterruptData::cpus`
+    pub per_cpu: Vec<f64>,
+    /// /proc/irq/<n>/smp_affinity_list (numbered IRQs only)
+    pub affinity: Option<String>,
+}
+
+impl IrqLine {
+    /// CPU (index into `per_cpu`) taking most of this line's interrupts and its share in %
+    pub fn busiest_cpu(&self) -> Option<(usize, f64)> {
+        let (cpu, &rate) = self.per_cpu
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))?;
+        (self.rate > 0.0).then(|| (cpu, rate / self.rate * 100.0))
+    }
+}
+
+/// One softirq type from /proc/softirqs
+#[derive(Clone, Default)]
+pub struct SoftirqLine {
+    pub name: String,
+    pub rate: f64,
+    pub per_cpu: Vec<f64>,
+}
+
+#[derive(Clone, Default)]
+pub struct InterruptData {
+    pub context_switch_rate: f64,
+    pub fork_rate: f64,
+    /// All interrupts (the /proc/stat "intr" total) per second
+    pub interrupt_rate: f64,
+    pub procs_running: u64,
+    pub procs_blocked: u64,
+    /// CPU numbers of the /proc/interrupts columns (offline CPUs are absent)
+    pub cpus: Vec<u32>,
+    /// Interrupts per second per CPU, summed over every line
+    pub per_cpu_rate: Vec<f64>,
+    /// Busiest first
+    pub irqs: Vec<IrqLine>,
+    pub softirqs: Vec<SoftirqLine>,
+}
+
+/// Raw counters of one refresh
+#[derive(Default)]
+struct Sample {
+    ctxt: u64,
+    forks: u64,
+    intr: u64,
+    irqs: HashMap<String, Vec<u64>>,
+    softirqs: HashMap<String, Vec<u64>>,
+}
+
+pub struct InterruptCollector {
+    pub data: InterruptData,
+    pub context_switch_history: History60,
+    pub interrupt_history: History60,
+    last: Option<Sample>,
+    last_update: Instant,
+}
+
+impl InterruptCollector {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            data: InterruptData::default(),
+            context_switch_history: History60::default(),
+            interrupt_history: History60::default(),
+            last: None,
+            last_update: Instant::now(),
+        })
+    }
+
+    pub fn collect(&mut self) -> Result<()> {
+        let elapsed = self.last_update.elapsed().as_secs_f64().max(0.001);
+        self.last_update = Instant::now();
+
+        let mut sample = Sample::default();
+        if let Ok(stat) = fs::read_to_string("/proc/stat") {
+            for line in stat.lines() {
+                let mut fields = line.split_whitespace();
+                let key = fields.next().unwrap_or_default();
+                let value = fields.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+                match key {
+                    "ctxt" => sample.ctxt = value,
+                    "processes" => sample.forks = value,
+                    "intr" => sample.intr = value,
+                    "procs_running" => self.data.procs_running = value,
+                    "procs_blocked" => self.data.procs_blocked = value,
+                    _ => {}
+                }
+            }
+        }
+
+        let interrupts = fs::read_to_string("/proc/interrupts").unwrap_or_default();
+        let (cpus, lines) = parse_per_cpu_table(&interrupts);
+        let softirqs = fs::read_to_string("/proc/softirqs").unwrap_or_default();
+        let (_, softirq_lines) = parse_per_cpu_table(&softirqs);
+
+        let rate = |now: u64, prev: u64| now.saturating_sub(prev) as f64 / elapsed;
+        let rates = |now: &[u64], prev: Option<&Vec<u64>>| -> Vec<f64> {
+            now.iter()
+                .enumerate()
+                .map(|(i, &n)| prev.and_then(|p| p.get(i)).map(|&p| rate(n, p)).unwrap_or(0.0))
+                .collect()
+        };
+
+        let last = self.last.as_ref();
+        let mut irqs = Vec::with_capacity(lines.len());
+        let mut per_cpu_rate = vec![0.0; cpus.len()];
+        for (irq, counts, text) in lines {
+            let per_cpu = rates(&counts, last.and_then(|l| l.irqs.get(&irq)));
+            for (total, r) in per_cpu_rate.iter_mut().zip(&per_cpu) {
+                *total += r;
+            }
+            let (chip, device) = split_description(&irq, &text);
+            let affinity = irq
+                .parse::<u32>()
+                .ok()
+                .and_then(|n| fs::read_to_string(format!("/proc/irq/{}/smp_affinity_list", n)).ok())
+                .map(|s| s.trim().to_string());
+            irqs.push(IrqLine {
+                rate: per_cpu.iter().sum(),
+                irq: irq.clone(),
+                chip,
+                device,
+                per_cpu,
+                affinity,
+            });
+            sample.irqs.insert(irq, counts);
+        }
+        irqs.sort_by(|a, b| {
+            b.rate
+                .partial_cmp(&a.rate)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.irq.cmp(&b.irq))
+        });
+
+        let mut softirq_rates = Vec::with_capacity(softirq_lines.len());
+        for (name, counts, _) in softirq_lines {
+            let per_cpu = rates(&counts, last.and_then(|l| l.softirqs.get(&name)));
+            softirq_rates.push(SoftirqLine { name: name.clone(), rate: per_cpu.iter().sum(), per_cpu });
+            sample.softirqs.insert(name, counts);
+        }
+
+        if let Some(last) = last {
+            self.data.context_switch_rate = rate(sample.ctxt, last.ctxt);
+            self.data.fork_rate = rate(sample.forks, last.forks);
+            self.data.interrupt_rate = rate(sample.intr, last.intr);
+        }
+        self.data.cpus = cpus;
+        self.data.per_cpu_rate = per_cpu_rate;
+        self.data.irqs = irqs;
+        self.data.softirqs = softirq_rates;
+        self.last = Some(sample);
+
+        // Update history
+        self.context_switch_history.push(self.data.context_switch_rate as f32);
+        self.interrupt_history.push(self.data.interrupt_rate as f32);
+
+        Ok(())
+    }
+}
+
+/// (key, per-CPU counts, description) of one /proc/interrupts or /proc/softirqs line
+type TableRow = (String, Vec<u64>, String);
+
+/// Parse /proc/interrupts or /proc/softirqs: a "CPU0 CPU1 ..." header, then
+/// "KEY: count count ... [description]" lines. Returns the CPU numbers and the rows.
+fn parse_per_cpu_table(content: &str) -> (Vec<u32>, Vec<TableRow>) {
+    let mut lines = content.lines();
+    let cpus: Vec<u32> = lines
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter_map(|c| c.strip_prefix("CPU")?.parse().ok())
+        .collect();
+
+    let rows = lines
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            let mut counts = Vec::with_capacity(cpus.len());
+            let mut remainder = rest.trim_start();
+            while counts.len() < cpus.len() {
+                let end = remainder.find(char::is_whitespace).unwrap_or(remainder.len());
+                let Ok(count) = remainder[..end].parse() else { break };
+                counts.push(count);
+                remainder = remainder[end..].trim_start();
+            }
+            // Lines such as ERR/MIS carry a single system-wide count
+            counts.resize(cpus.len(), 0);
+            Some((key.trim().to_string(), counts, remainder.trim().to_string()))
+        })
+        .collect();
+    (cpus, rows)
+}
+
+/// "IR-PCI-MSIX-0000:00:1f.6   0-edge      eth0-TxRx-0" → ("IR-PCI-MSIX-0000:00:1f.6 0-edge", "eth0-TxRx-0")
+fn split_description(irq: &str, text: &str) -> (String, String) {
+    if irq.parse::<u32>().is_err() {
+        return (String::new(), text.to_string());
+    }
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    // The trigger field ("5-edge", "9-fasteoi" on x86, "Level" / "Edge" on ARM GIC) closes the controller part
+    let is_trigger = |field: &str| {
+        let field = field.to_ascii_lowercase();
+        ["edge", "level", "fasteoi"]
+            .iter()
+            .any(|t| field == *t || field.ends_with(&format!("-{}", t)))
+    };
+    match fields.iter().position(|f| is_trigger(f)) {
+        Some(i) => (fields[..=i].join(" "), fields[i + 1..].join(" ")),
+        None => match fields.split_first() {
+            Some((chip, rest)) => (chip.to_string(), rest.join(" ")),
+            None => (String::new(), String::new()),
+        },
+    }
+}
+
+/// Compact events-per-second count: "950", "12.3K", "1.2M"
+pub fn format_rate(rate: f64) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.1}M", rate / 1_000_000.0)
+    } else if rate >= 1_000.0 {
+        format!("{:.1}K", rate / 1_000.0)
+    } else {
+        format!("{:.0}", rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_description_x86() {
+        let (chip, device) = split_description("28", "IR-PCI-MSIX-0000:00:1f.6   0-edge      eth0-TxRx-0");
+        assert_eq!(chip, "IR-PCI-MSIX-0000:00:1f.6 0-edge");
+        assert_eq!(device, "eth0-TxRx-0");
+
+        let (chip, device) = split_description("9", "IR-IO-APIC    9-fasteoi   acpi");
+        assert_eq!(chip, "IR-IO-APIC 9-fasteoi");
+        assert_eq!(device, "acpi");
+    }
+
+    #[test]
+    fn split_description_gic() {
+        let (chip, device) = split_description("11", "GICv3  27 Level     arch_timer");
+        assert_eq!(chip, "GICv3 27 Level");
+        assert_eq!(device, "arch_timer");
+
+        let (chip, device) = split_description("50", "ITS-MSI 16384 Edge      PCIe PME, aerdrv");
+        assert_eq!(chip, "ITS-MSI 16384 Edge");
+        assert_eq!(device, "PCIe PME, aerdrv");
+    }
+
+    #[test]
+    fn split_description_named_line() {
+        let (chip, device) = split_description("NMI", "Non-maskable interrupts");
+        assert_eq!(chip, "");
+        assert_eq!(device, "Non-maskable interrupts");
+    }
+}
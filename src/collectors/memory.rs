@@ -1,4 +1,9 @@
 use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
 use sysinfo::System;
 
 use super::History60;
@@ -12,12 +17,156 @@ pub struct MemoryData {
     pub swap_total: u64,
     pub swap_used: u64,
     pub swap_percent: f32,
+    /// Breakdown from /proc/meminfo (None off Linux)
+    pub meminfo: Option<MemInfo>,
+    /// /proc/vmstat rates (None until two samples exist, or off Linux)
+    pub paging: Option<PagingRates>,
+    /// OOM kills seen since the monitor started
+    pub oom_kills: u64,
+    pub last_oom_kill: Option<DateTime<Local>>,
+    /// Per NUMA node (empty unless the machine has several)
+    pub nodes: Vec<NodeMemory>,
+    /// Initialised zram devices
+    pub zram: Vec<ZramDevice>,
+    /// None when zswap is off and holds nothing
+    pub zswap: Option<CompressedPool>,
+}
+
+/// /sys/devices/system/node/nodeN/meminfo, in bytes
+#[derive(Clone, Copy, Default)]
+pub struct NodeMemory {
+    pub node: u32,
+    pub total: u64,
+    pub free: u64,
+    /// Page cache on this node
+    pub file: u64,
+}
+
+impl NodeMemory {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+}
+
+/// Pages stored compressed: what was put in and what it takes up in RAM
+#[derive(Clone, Copy, Default)]
+pub struct CompressedPool {
+    pub original: u64,
+    pub compressed: u64,
+}
+
+impl CompressedPool {
+    /// Compression ratio (original / compressed), 0 when empty
+    pub fn ratio(&self) -> f32 {
+        if self.compressed > 0 {
+            self.original as f32 / self.compressed as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+/// /sys/block/zramN
+#[derive(Clone, Default)]
+pub struct ZramDevice {
+    pub name: String,
+    /// Configured (uncompressed) capacity
+    pub disk_size: u64,
+    pub pool: CompressedPool,
+    /// RAM taken including allocator overhead
+    pub memory_used: u64,
+    pub algorithm: String,
+}
+
+/// Paging activity per second from /proc/vmstat deltas
+#[derive(Clone, Copy, Default)]
+pub struct PagingRates {
+    /// Pages swapped in / out
+    pub swap_in: f64,
+    pub swap_out: f64,
+    pub faults: f64,
+    /// Faults that needed disk I/O
+    pub major_faults: f64,
+    /// Pages scanned and reclaimed by kswapd and direct reclaim
+    pub scanned: f64,
+    pub stolen: f64,
+}
+
+/// Raw /proc/vmstat counters
+#[derive(Clone, Copy, Default)]
+struct VmStat {
+    pswpin: u64,
+    pswpout: u64,
+    pgfault: u64,
+    pgmajfault: u64,
+    pgscan: u64,
+    pgsteal: u64,
+    oom_kill: u64,
+}
+
+/// /proc/meminfo fields, in bytes
+#[derive(Clone, Copy, Default)]
+pub struct MemInfo {
+    pub total: u64,
+    pub free: u64,
+    pub buffers: u64,
+    /// Page cache including shmem
+    pub cached: u64,
+    pub shmem: u64,
+    pub slab_reclaimable: u64,
+    pub slab_unreclaimable: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+    pub mapped: u64,
+    pub anon: u64,
+    /// Memory reserved for hugetlbfs pages, used or not
+    pub hugepages: u64,
+    pub hugepages_free: u64,
+    pub committed: u64,
+    pub commit_limit: u64,
+    /// Zswapped / Zswap (kernel 5.19+)
+    pub zswap: Option<CompressedPool>,
+}
+
+impl MemInfo {
+    /// File-backed cache that can be dropped under pressure (shmem cannot)
+    pub fn page_cache(&self) -> u64 {
+        self.cached.saturating_sub(self.shmem)
+    }
+
+    /// Used memory not in any other field: kernel stacks, page tables, vmalloc, drivers
+    pub fn kernel_other(&self) -> u64 {
+        self.total
+            .saturating_sub(self.free)
+            .saturating_sub(self.anon + self.cached + self.buffers)
+            .saturating_sub(self.slab_reclaimable + self.slab_unreclaimable + self.hugepages)
+    }
+
+    /// Committed_AS as a percentage of CommitLimit (can exceed 100 with overcommit)
+    pub fn commit_percent(&self) -> f32 {
+        if self.commit_limit > 0 {
+            self.committed as f32 / self.commit_limit as f32 * 100.0
+        } else {
+            0.0
+        }
+    }
 }
 
 pub struct MemoryCollector {
     system: System,
     pub data: MemoryData,
     pub history: History60,
+    /// Swap in / swap out / major faults per second
+    pub swap_in_history: History60,
+    pub swap_out_history: History60,
+    pub major_fault_history: History60,
+    /// All page faults per second
+    pub fault_history: History60,
+    /// Pages scanned / reclaimed (pgscan / pgsteal) per second
+    pub scan_history: History60,
+    pub steal_history: History60,
+    last_vmstat: Option<VmStat>,
+    last_update: Instant,
 }
 
 impl MemoryCollector {
@@ -29,6 +178,14 @@ impl MemoryCollector {
             system,
             data: MemoryData::default(),
             history: History60::default(),
+            swap_in_history: History60::default(),
+            swap_out_history: History60::default(),
+            major_fault_history: History60::default(),
+            fault_history: History60::default(),
+            scan_history: History60::default(),
+            steal_history: History60::default(),
+            last_vmstat: read_vmstat(),
+            last_update: Instant::now(),
         })
     }
 
@@ -53,13 +210,209 @@ impl MemoryCollector {
             0.0
         };
 
+        self.data.meminfo = read_meminfo();
+        self.data.nodes = read_numa_nodes();
+        self.data.zram = read_zram();
+        self.data.zswap = read_zswap(self.data.meminfo.as_ref());
+
+        // Paging rates and OOM kills from /proc/vmstat deltas
+        let elapsed = self.last_update.elapsed().as_secs_f64().max(0.001);
+        self.last_update = Instant::now();
+        let vmstat = read_vmstat();
+        if let (Some(prev), Some(now)) = (self.last_vmstat, vmstat) {
+            let rate = |now: u64, prev: u64| now.saturating_sub(prev) as f64 / elapsed;
+            self.data.paging = Some(PagingRates {
+                swap_in: rate(now.pswpin, prev.pswpin),
+                swap_out: rate(now.pswpout, prev.pswpout),
+                faults: rate(now.pgfault, prev.pgfault),
+                major_faults: rate(now.pgmajfault, prev.pgmajfault),
+                scanned: rate(now.pgscan, prev.pgscan),
+                stolen: rate(now.pgsteal, prev.pgsteal),
+            });
+            if now.oom_kill > prev.oom_kill {
+                self.data.oom_kills += now.oom_kill - prev.oom_kill;
+                self.data.last_oom_kill = Some(Local::now());
+            }
+        }
+        self.last_vmstat = vmstat;
+
         // Update history
         self.history.push(self.data.usage_percent);
+        if let Some(paging) = self.data.paging {
+            self.swap_in_history.push(paging.swap_in as f32);
+            self.swap_out_history.push(paging.swap_out as f32);
+            self.major_fault_history.push(paging.major_faults as f32);
+            self.fault_history.push(paging.faults as f32);
+            self.scan_history.push(paging.scanned as f32);
+            self.steal_history.push(paging.stolen as f32);
+        }
 
         Ok(())
     }
 }
 
+/// Parse /proc/meminfo ("Key:   1234 kB" lines; HugePages_* are page counts)
+fn read_meminfo() -> Option<MemInfo> {
+    let content = fs::read_to_string("/proc/meminfo").ok()?;
+    let fields: HashMap<&str, u64> = content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            Some((key, value.split_whitespace().next()?.parse().ok()?))
+        })
+        .collect();
+    let kb = |key: &str| fields.get(key).copied().unwrap_or(0) * 1024;
+
+    let page_size = kb("Hugepagesize");
+    let hugepages = match fields.get("Hugetlb") {
+        Some(&total) => total * 1024,
+        None => fields.get("HugePages_Total").copied().unwrap_or(0) * page_size,
+    };
+    Some(MemInfo {
+        total: kb("MemTotal"),
+        free: kb("MemFree"),
+        buffers: kb("Buffers"),
+        cached: kb("Cached"),
+        shmem: kb("Shmem"),
+        slab_reclaimable: kb("SReclaimable"),
+        slab_unreclaimable: kb("SUnreclaim"),
+        dirty: kb("Dirty"),
+        writeback: kb("Writeback"),
+        mapped: kb("Mapped"),
+        anon: kb("AnonPages"),
+        hugepages,
+        hugepages_free: fields.get("HugePages_Free").copied().unwrap_or(0) * page_size,
+        committed: kb("Committed_AS"),
+        commit_limit: kb("CommitLimit"),
+        zswap: fields.contains_key("Zswap").then(|| CompressedPool {
+            original: kb("Zswapped"),
+            compressed: kb("Zswap"),
+        }),
+    })
+}
+
+/// Per-node totals; only returned for machines with more than one node
+fn read_numa_nodes() -> Vec<NodeMemory> {
+    let Ok(entries) = fs::read_dir("/sys/devices/system/node") else {
+        return Vec::new();
+    };
+    let mut nodes: Vec<NodeMemory> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let node = entry.file_name().to_string_lossy().strip_prefix("node")?.parse().ok()?;
+            // "Node 0 MemTotal:       32768000 kB"
+            let content = fs::read_to_string(entry.path().join("meminfo")).ok()?;
+            let mut memory = NodeMemory { node, ..Default::default() };
+            for line in content.lines() {
+                let Some((key, value)) = line.split_once(':') else { continue };
+                let bytes = value.split_whitespace().next().and_then(|v| v.parse::<u64>().ok()).unwrap_or(0) * 1024;
+                match key.split_whitespace().last() {
+                    Some("MemTotal") => memory.total = bytes,
+                    Some("MemFree") => memory.free = bytes,
+                    Some("FilePages") => memory.file = bytes,
+                    _ => {}
+                }
+            }
+            // Memory-less nodes (CPUs only) have nothing to show
+            (memory.total > 0).then_some(memory)
+        })
+        .collect();
+    if nodes.len() < 2 {
+        return Vec::new();
+    }
+    nodes.sort_by_key(|n| n.node);
+    nodes
+}
+
+/// zram devices with a disk size set; mm_stat starts with
+/// orig_data_size compr_data_size mem_used_total
+fn read_zram() -> Vec<ZramDevice> {
+    let Ok(entries) = fs::read_dir("/sys/block") else {
+        return Vec::new();
+    };
+    let mut devices: Vec<ZramDevice> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("zram"))
+        .filter_map(|entry| {
+            let dir = entry.path();
+            let read = |name: &str| fs::read_to_string(dir.join(name)).ok();
+            let disk_size: u64 = read("disksize")?.trim().parse().ok()?;
+            if disk_size == 0 {
+                return None;
+            }
+            let stats: Vec<u64> = read("mm_stat")?.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+            // The active algorithm is the bracketed one: "lzo [lz4] zstd"
+            let algorithm = read("comp_algorithm")
+                .and_then(|a| a.split_whitespace().find_map(|w| w.strip_prefix('[')?.strip_suffix(']').map(String::from)))
+                .unwrap_or_default();
+            Some(ZramDevice {
+                name: entry.file_name().to_string_lossy().to_string(),
+                disk_size,
+                pool: CompressedPool {
+                    original: stats.first().copied().unwrap_or(0),
+                    compressed: stats.get(1).copied().unwrap_or(0),
+                },
+                memory_used: stats.get(2).copied().unwrap_or(0),
+                algorithm,
+            })
+        })
+        .collect();
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+    devices
+}
+
+/// zswap pool: meminfo's Zswap/Zswapped when present, else debugfs (root only).
+/// None when zswap is disabled and empty.
+fn read_zswap(meminfo: Option<&MemInfo>) -> Option<CompressedPool> {
+    let enabled = fs::read_to_string("/sys/module/zswap/parameters/enabled")
+        .is_ok_and(|v| v.trim() == "Y");
+    let pool = meminfo.and_then(|m| m.zswap).or_else(|| {
+        let debug = Path::new("/sys/kernel/debug/zswap");
+        let read = |name: &str| fs::read_to_string(debug.join(name)).ok()?.trim().parse::<u64>().ok();
+        Some(CompressedPool {
+            // stored_pages counts 4 KiB pages
+            original: read("stored_pages")? * 4096,
+            compressed: read("pool_total_size")?,
+        })
+    });
+    match pool {
+        Some(pool) if enabled || pool.original > 0 => Some(pool),
+        None if enabled => Some(CompressedPool::default()),
+        _ => None,
+    }
+}
+
+/// Parse the /proc/vmstat counters we track. Reclaim is summed over its sources;
+/// pgscan_anon/_file split the same pages again and are skipped.
+fn read_vmstat() -> Option<VmStat> {
+    const RECLAIMERS: [&str; 4] = ["kswapd", "direct", "khugepaged", "proactive"];
+    let content = fs::read_to_string("/proc/vmstat").ok()?;
+    let mut stat = VmStat::default();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(' ') else { continue };
+        let Ok(value) = value.trim().parse::<u64>() else { continue };
+        match key {
+            "pswpin" => stat.pswpin = value,
+            "pswpout" => stat.pswpout = value,
+            "pgfault" => stat.pgfault = value,
+            "pgmajfault" => stat.pgmajfault = value,
+            "oom_kill" => stat.oom_kill = value,
+            _ => {
+                if let Some(source) = key.strip_prefix("pgscan_") {
+                    if RECLAIMERS.contains(&source) {
+                        stat.pgscan += value;
+                    }
+                } else if let Some(source) = key.strip_prefix("pgsteal_") {
+                    if RECLAIMERS.contains(&source) {
+                        stat.pgsteal += value;
+                    }
+                }
+            }
+        }
+    }
+    Some(stat)
+}
+
 /// Format bytes to human readable string
 pub fn format_bytes(bytes: u64) -> String {
     const GB: u64 = 1024 * 1024 * 1024;
@@ -5,6 +5,11 @@ pub mod gpu;
 pub mod network;
 pub mod disk;
 pub mod process;
+pub mod cgroup;
+pub mod pressure;
+pub mod sensors;
+pub mod interrupts;
+pub mod watch;
 
 pub use cpu::CpuCollector;
 pub use memory::MemoryCollector;
@@ -13,6 +18,9 @@ pub use gpu::GpuCollector;
 pub use network::NetworkCollector;
 pub use disk::DiskCollector;
 pub use process::ProcessCollector;
+pub use pressure::PressureCollector;
+pub use sensors::SensorCollector;
+pub use interrupts::InterruptCollector;
 
 use anyhow::Result;
 use crate::config::Config;
@@ -76,6 +84,9 @@ pub struct Collectors {
     pub network: NetworkCollector,
     pub disk: DiskCollector,
     pub process: ProcessCollector,
+    pub pressure: PressureCollector,
+    pub sensors: SensorCollector,
+    pub interrupts: InterruptCollector,
 }
 
 impl Collectors {
@@ -84,14 +95,13 @@ impl Collectors {
             cpu: CpuCollector::new()?,
             memory: MemoryCollector::new()?,
             system: SystemCollector::new()?,
-            gpu: if config.gpu_enabled {
-                GpuCollector::new()?
-            } else {
-                GpuCollector::new()? // Will just report unavailable
-            },
+            gpu: GpuCollector::new(config.gpu_enabled)?,
             network: NetworkCollector::new()?,
-            disk: DiskCollector::new()?,
-            process: ProcessCollector::new()?,
+            disk: DiskCollector::new(config)?,
+            process: ProcessCollector::new(config.cpu_mode, &config.watch_targets())?,
+            pressure: PressureCollector::new()?,
+            sensors: SensorCollector::new()?,
+            interrupts: InterruptCollector::new()?,
         })
     }
 
@@ -103,6 +113,9 @@ impl Collectors {
         self.network.collect()?;
         self.disk.collect()?;
         self.process.collect()?;
+        self.pressure.collect()?;
+        self.sensors.collect()?;
+        self.interrupts.collect()?;
         Ok(())
     }
 }
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use std::fs;
+use sysinfo::System;
+
+use super::History60;
+
+/// One PSI line: share of wall time tasks were stalled (%), and total stall time
+#[derive(Clone, Copy, Default)]
+pub struct PsiLine {
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+    /// Cumulative stall time in microseconds
+    pub total_us: u64,
+}
+
+/// /proc/pressure/<resource>: "some" tasks stalled, and "full" (all non-idle tasks stalled)
+#[derive(Clone, Copy, Default)]
+pub struct Pressure {
+    pub some: PsiLine,
+    /// Missing for CPU on kernels before 5.13
+    pub full: Option<PsiLine>,
+}
+
+#[derive(Clone, Default)]
+pub struct PressureData {
+    pub load_1: f64,
+    pub load_5: f64,
+    pub load_15: f64,
+    /// Runnable and total scheduling entities from /proc/loadavg
+    pub runnable: u32,
+    pub tasks: u32,
+    pub cpu: Option<Pressure>,
+    pub memory: Option<Pressure>,
+    pub io: Option<Pressure>,
+}
+
+impl PressureData {
+    /// PSI is readable (kernel 4.20+ built with CONFIG_PSI and not booted with psi=0)
+    pub fn psi_available(&self) -> bool {
+        self.cpu.is_some() || self.memory.is_some() || self.io.is_some()
+    }
+}
+
+pub struct PressureCollector {
+    pub data: PressureData,
+    pub load_history: History60,
+    /// "some" avg10 per resource
+    pub cpu_history: History60,
+    pub memory_history: History60,
+    pub io_history: History60,
+}
+
+impl PressureCollector {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            data: PressureData::default(),
+            load_history: History60::default(),
+            cpu_history: History60::default(),
+            memory_history: History60::default(),
+            io_history: History60::default(),
+        })
+    }
+
+    pub fn collect(&mut self) -> Result<()> {
+        match read_loadavg() {
+            Some((load, runnable, tasks)) => {
+                [self.data.load_1, self.data.load_5, self.data.load_15] = load;
+                self.data.runnable = runnable;
+                self.data.tasks = tasks;
+            }
+            None => {
+                let load = System::load_average();
+                self.data.load_1 = load.one;
+                self.data.load_5 = load.five;
+                self.data.load_15 = load.fifteen;
+            }
+        }
+
+        self.data.cpu = read_pressure("cpu");
+        self.data.memory = read_pressure("memory");
+        self.data.io = read_pressure("io");
+
+        // Update history (load scaled x100 so fractions survive the integer sparkline)
+        self.load_history.push((self.data.load_1 * 100.0) as f32);
+        let some = |p: Option<Pressure>| p.map(|p| p.some.avg10).unwrap_or(0.0);
+        self.cpu_history.push(some(self.data.cpu));
+        self.memory_history.push(some(self.data.memory));
+        self.io_history.push(some(self.data.io));
+
+        Ok(())
+    }
+}
+
+/// "0.16 0.17 0.16 2/77 19269" → ([1, 5, 15 min], runnable, total)
+fn read_loadavg() -> Option<([f64; 3], u32, u32)> {
+    let content = fs::read_to_string("/proc/loadavg").ok()?;
+    let mut fields = content.split_whitespace();
+    let mut load = [0.0; 3];
+    for slot in &mut load {
+        *slot = fields.next()?.parse().ok()?;
+    }
+    let (runnable, tasks) = fields.next()?.split_once('/')?;
+    Some((load, runnable.parse().ok()?, tasks.parse().ok()?))
+}
+
+/// Parse /proc/pressure/<resource>; None when PSI is missing or disabled
+fn read_pressure(resource: &str) -> Option<Pressure> {
+    let content = fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+    let mut pressure = Pressure::default();
+    let mut found = false;
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let mut psi = PsiLine::default();
+        for field in fields {
+            let Some((key, value)) = field.split_once('=') else { continue };
+            match key {
+                "avg10" => psi.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => psi.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => psi.avg300 = value.parse().unwrap_or(0.0),
+                "total" => psi.total_us = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        match kind {
+            Some("some") => {
+                pressure.some = psi;
+                found = true;
+            }
+            Some("full") => pressure.full = Some(psi),
+            _ => {}
+        }
+    }
+    found.then_some(pressure)
+}
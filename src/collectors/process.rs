@@ -1,16 +1,49 @@
 use anyhow::Result;
-use sysinfo::{System, ProcessesToUpdate, ProcessStatus};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::time::Instant;
+use regex::Regex;
+use sysinfo::{Pid, System, ProcessesToUpdate, ProcessRefreshKind, ProcessStatus, ThreadKind, Uid, UpdateKind, Users};
+
+use super::History60;
+use super::cgroup::{self, CgroupUsage};
+use super::watch::{WatchTarget, WatchedProcess};
+use crate::config::CpuMode;
 
-#[allow(dead_code)]
 #[derive(Clone, Default)]
 pub struct ProcessInfo {
     pub pid: u32,
+    pub ppid: Option<u32>,
     pub name: String,
+    /// Full command line, space separated (empty for kernel threads)
+    pub command: String,
+    pub uid: Option<u32>,
+    pub user: String,
+    /// Cgroup path ("/" when unknown)
+    pub cgroup: String,
+    pub kernel_thread: bool,
     pub cpu_percent: f32,
     pub memory_bytes: u64,
     pub memory_percent: f32,
+    /// Disk I/O since the previous refresh, in bytes/sec
+    pub read_rate: f64,
+    pub write_rate: f64,
     pub threads: usize,
     pub status: String,
+    /// Start time, seconds since the epoch
+    pub start_time: u64,
+}
+
+/// Resources used by all processes of one user
+#[derive(Clone, Default)]
+pub struct UserUsage {
+    pub user: String,
+    pub uid: Option<u32>,
+    pub processes: usize,
+    pub threads: usize,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
 }
 
 #[derive(Clone, Default)]
@@ -22,31 +55,434 @@ pub struct ProcessData {
     pub zombies: usize,
     pub top_cpu: Vec<ProcessInfo>,
     pub top_memory: Vec<ProcessInfo>,
+    pub top_io: Vec<ProcessInfo>,
+    /// Totals per owning user, busiest first
+    pub users: Vec<UserUsage>,
+    /// Totals per cgroup (service, container, pod), busiest first
+    pub cgroups: Vec<CgroupUsage>,
+    /// Every process from the last refresh (unsorted)
+    pub processes: Vec<ProcessInfo>,
+}
+
+impl ProcessInfo {
+    /// Combined read + write rate (bytes/sec)
+    pub fn io_rate(&self) -> f64 {
+        self.read_rate + self.write_rate
+    }
+}
+
+/// Column used to order the full process table
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortColumn {
+    Pid,
+    Name,
+    #[default]
+    Cpu,
+    Memory,
+    Io,
+    Threads,
+    Status,
+}
+
+impl SortColumn {
+    pub const ALL: [SortColumn; 7] = [
+        SortColumn::Pid,
+        SortColumn::Name,
+        SortColumn::Cpu,
+        SortColumn::Memory,
+        SortColumn::Io,
+        SortColumn::Threads,
+        SortColumn::Status,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortColumn::Pid => "PID",
+            SortColumn::Name => "NAME",
+            SortColumn::Cpu => "CPU%",
+            SortColumn::Memory => "MEM",
+            SortColumn::Io => "I/O R/W",
+            SortColumn::Threads => "THR",
+            SortColumn::Status => "STATUS",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&c| c == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let idx = Self::ALL.iter().position(|&c| c == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Numeric columns read best biggest-first, text columns A-Z
+    pub fn default_descending(self) -> bool {
+        matches!(self, SortColumn::Cpu | SortColumn::Memory | SortColumn::Io | SortColumn::Threads)
+    }
+
+    /// Compare two processes by this column (ascending)
+    pub fn compare(self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+        match self {
+            SortColumn::Pid => a.pid.cmp(&b.pid),
+            SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortColumn::Cpu => a.cpu_percent.partial_cmp(&b.cpu_percent).unwrap_or(Ordering::Equal),
+            SortColumn::Memory => a.memory_bytes.cmp(&b.memory_bytes),
+            SortColumn::Io => a.io_rate().partial_cmp(&b.io_rate()).unwrap_or(Ordering::Equal),
+            SortColumn::Threads => a.threads.cmp(&b.threads),
+            SortColumn::Status => a.status.cmp(&b.status),
+        }
+    }
+}
+
+/// Sort processes by column, falling back to PID so rows don't jump around on ties
+pub fn sort_processes(list: &mut [&ProcessInfo], column: SortColumn, descending: bool) {
+    list.sort_by(|a, b| {
+        let ord = column.compare(a, b);
+        let ord = if descending { ord.reverse() } else { ord };
+        ord.then_with(|| a.pid.cmp(&b.pid))
+    });
+}
+
+/// Parsed `/` search: `pid:N`, `user:NAME`, `cmd:TEXT`, `cgroup:PATH`, `re:REGEX`, or plain
+/// text matched against the name (and PID when numeric)
+#[derive(Default)]
+pub enum ProcessFilter {
+    #[default]
+    None,
+    Text(String),
+    Command(String),
+    Regex(Regex),
+    User(String),
+    Cgroup(String),
+    Pid(u32),
+    /// Regex that failed to compile; matches everything
+    Invalid(String),
 }
 
+impl ProcessFilter {
+    pub fn parse(input: &str) -> Self {
+        let input = input.trim();
+        if input.is_empty() {
+            return ProcessFilter::None;
+        }
+        if let Some(pattern) = input.strip_prefix("re:") {
+            return match Regex::new(&format!("(?i){}", pattern)) {
+                Ok(re) => ProcessFilter::Regex(re),
+                Err(e) => ProcessFilter::Invalid(e.to_string()),
+            };
+        }
+        if let Some(text) = input.strip_prefix("cmd:") {
+            return ProcessFilter::Command(text.to_lowercase());
+        }
+        if let Some(user) = input.strip_prefix("user:").or_else(|| input.strip_prefix("u:")) {
+            return ProcessFilter::User(user.to_string());
+        }
+        if let Some(path) = input.strip_prefix("cgroup:") {
+            return ProcessFilter::Cgroup(path.to_string());
+        }
+        if let Some(pid) = input.strip_prefix("pid:").and_then(|p| p.trim().parse().ok()) {
+            return ProcessFilter::Pid(pid);
+        }
+        ProcessFilter::Text(input.to_lowercase())
+    }
+
+    pub fn is_active(&self) -> bool {
+        !matches!(self, ProcessFilter::None | ProcessFilter::Invalid(_))
+    }
+
+    pub fn matches(&self, p: &ProcessInfo) -> bool {
+        match self {
+            ProcessFilter::None | ProcessFilter::Invalid(_) => true,
+            ProcessFilter::Text(text) => {
+                p.name.to_lowercase().contains(text) || p.pid.to_string() == *text
+            }
+            ProcessFilter::Command(text) => p.command.to_lowercase().contains(text),
+            ProcessFilter::Regex(re) => re.is_match(&p.name) || re.is_match(&p.command),
+            ProcessFilter::User(user) => p.user == *user || p.uid.is_some_and(|u| u.to_string() == *user),
+            ProcessFilter::Cgroup(path) => p.cgroup == *path,
+            ProcessFilter::Pid(pid) => p.pid == *pid,
+        }
+    }
+}
+
+/// One line of the process table, either flat or as part of the tree
+pub struct ProcessRow<'a> {
+    pub info: &'a ProcessInfo,
+    /// Tree guides drawn before the name (e.g. "│  ├─ "), empty in flat mode
+    pub prefix: String,
+    pub has_children: bool,
+    pub collapsed: bool,
+    /// CPU and memory of the process plus all of its descendants
+    pub subtree_cpu: f32,
+    pub subtree_memory: u64,
+}
+
+/// Rows for the flat (non-tree) process table
+pub fn flat_rows<'a>(list: &[&'a ProcessInfo], column: SortColumn, descending: bool) -> Vec<ProcessRow<'a>> {
+    let mut list = list.to_vec();
+    sort_processes(&mut list, column, descending);
+    list.into_iter()
+        .map(|info| ProcessRow {
+            info,
+            prefix: String::new(),
+            has_children: false,
+            collapsed: false,
+            subtree_cpu: info.cpu_percent,
+            subtree_memory: info.memory_bytes,
+        })
+        .collect()
+}
+
+/// Rows for the process tree: siblings sorted by column, collapsed subtrees hidden.
+/// Processes whose parent is not in `list` become roots.
+pub fn tree_rows<'a>(
+    list: &[&'a ProcessInfo],
+    column: SortColumn,
+    descending: bool,
+    collapsed: &HashSet<u32>,
+) -> Vec<ProcessRow<'a>> {
+    let known: HashSet<u32> = list.iter().map(|p| p.pid).collect();
+    let mut children: HashMap<u32, Vec<&'a ProcessInfo>> = HashMap::new();
+    let mut roots: Vec<&'a ProcessInfo> = Vec::new();
+
+    for &p in list {
+        match p.ppid {
+            Some(ppid) if ppid != p.pid && known.contains(&ppid) => {
+                children.entry(ppid).or_default().push(p)
+            }
+            _ => roots.push(p),
+        }
+    }
+
+    sort_processes(&mut roots, column, descending);
+    for siblings in children.values_mut() {
+        sort_processes(siblings, column, descending);
+    }
+
+    let mut totals: HashMap<u32, (f32, u64)> = HashMap::with_capacity(list.len());
+    for root in &roots {
+        subtree_totals(root, &children, &mut totals);
+    }
+
+    let mut rows = Vec::with_capacity(list.len());
+    let last_root = roots.len().saturating_sub(1);
+    for (i, root) in roots.iter().enumerate() {
+        push_tree_rows(root, "", i == last_root, true, &children, &totals, collapsed, &mut rows);
+    }
+    rows
+}
+
+fn subtree_totals(
+    p: &ProcessInfo,
+    children: &HashMap<u32, Vec<&ProcessInfo>>,
+    totals: &mut HashMap<u32, (f32, u64)>,
+) -> (f32, u64) {
+    let mut sum = (p.cpu_percent, p.memory_bytes);
+    if let Some(kids) = children.get(&p.pid) {
+        for kid in kids {
+            let (cpu, mem) = subtree_totals(kid, children, totals);
+            sum.0 += cpu;
+            sum.1 += mem;
+        }
+    }
+    totals.insert(p.pid, sum);
+    sum
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_tree_rows<'a>(
+    p: &'a ProcessInfo,
+    indent: &str,
+    is_last: bool,
+    is_root: bool,
+    children: &HashMap<u32, Vec<&'a ProcessInfo>>,
+    totals: &HashMap<u32, (f32, u64)>,
+    collapsed: &HashSet<u32>,
+    rows: &mut Vec<ProcessRow<'a>>,
+) {
+    let kids = children.get(&p.pid);
+    let has_children = kids.is_some_and(|k| !k.is_empty());
+    let is_collapsed = has_children && collapsed.contains(&p.pid);
+    let (subtree_cpu, subtree_memory) = totals.get(&p.pid).copied().unwrap_or((p.cpu_percent, p.memory_bytes));
+
+    let prefix = if is_root {
+        String::new()
+    } else if is_last {
+        format!("{}└─ ", indent)
+    } else {
+        format!("{}├─ ", indent)
+    };
+
+    rows.push(ProcessRow {
+        info: p,
+        prefix,
+        has_children,
+        collapsed: is_collapsed,
+        subtree_cpu,
+        subtree_memory,
+    });
+
+    if is_collapsed {
+        return;
+    }
+    if let Some(kids) = kids {
+        let child_indent = if is_root {
+            String::new()
+        } else if is_last {
+            format!("{}   ", indent)
+        } else {
+            format!("{}│  ", indent)
+        };
+        let last = kids.len().saturating_sub(1);
+        for (i, kid) in kids.iter().enumerate() {
+            push_tree_rows(kid, &child_indent, i == last, false, children, totals, collapsed, rows);
+        }
+    }
+}
+
+/// PIDs of `root` and all its descendants, deepest first so parents go last
+pub fn subtree_pids(list: &[ProcessInfo], root: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for p in list {
+        if let Some(ppid) = p.ppid {
+            if ppid != p.pid {
+                children.entry(ppid).or_default().push(p.pid);
+            }
+        }
+    }
+
+    let mut order = Vec::new();
+    let mut stack = vec![root];
+    let mut seen = HashSet::new();
+    while let Some(pid) = stack.pop() {
+        if !seen.insert(pid) {
+            continue;
+        }
+        order.push(pid);
+        if let Some(kids) = children.get(&pid) {
+            stack.extend(kids);
+        }
+    }
+    order.reverse();
+    order
+}
+
+/// Everything the detail pane shows about one process, mostly from /proc/<pid>
+#[derive(Clone, Default)]
+pub struct ProcessDetails {
+    pub name: String,
+    pub command: Vec<String>,
+    pub exe: Option<String>,
+    pub cwd: Option<String>,
+    pub environ: Vec<String>,
+    /// Unix timestamp (seconds)
+    pub start_time: u64,
+    pub uid: Option<u32>,
+    pub gid: Option<u32>,
+    pub user: String,
+    pub nice: Option<i32>,
+    pub policy: Option<&'static str>,
+    pub cgroup: Option<String>,
+    pub fd_count: Option<usize>,
+}
+
+/// Process shown in the detail pane, with its own history
+pub struct InspectedProcess {
+    pub pid: u32,
+    pub details: ProcessDetails,
+    pub cpu_history: History60,
+    /// Resident memory in MB
+    pub rss_history: History60,
+    pub exited: bool,
+}
+
+/// Refreshes between re-reads of a process's /proc/<pid>/cgroup
+const CGROUP_REFRESH_TICKS: u64 = 5;
+
 pub struct ProcessCollector {
     system: System,
+    users: Users,
     pub data: ProcessData,
+    pub inspected: Option<InspectedProcess>,
+    /// UID this monitor runs as (for "only my processes")
+    pub own_uid: Option<u32>,
     total_memory: u64,
+    last_update: Instant,
+    /// Cgroup path per (PID, start time), re-read every few refreshes since processes can be moved
+    cgroup_paths: HashMap<(u32, u64), String>,
+    /// Refresh counter, spreads the cgroup re-reads over ticks
+    tick: u64,
+    cgroup_root: Option<std::path::PathBuf>,
+    /// throttled_usec per cgroup path at the previous refresh
+    last_throttled: HashMap<String, u64>,
+    /// Pinned PIDs and name patterns, with history kept across refreshes
+    pub watched: Vec<WatchedProcess>,
+    /// Scale of every CPU% this collector reports
+    pub cpu_mode: CpuMode,
+    cpu_count: usize,
 }
 
 impl ProcessCollector {
-    pub fn new() -> Result<Self> {
+    pub fn new(cpu_mode: CpuMode, watch: &[String]) -> Result<Self> {
         let mut system = System::new_all();
         system.refresh_all();
         let total_memory = system.total_memory();
+        let cpu_count = system.cpus().len().max(1);
+        let own_uid = sysinfo::get_current_pid()
+            .ok()
+            .and_then(|pid| system.process(pid))
+            .and_then(|p| p.user_id())
+            .and_then(uid_number);
 
         Ok(Self {
             system,
+            users: Users::new_with_refreshed_list(),
             data: ProcessData::default(),
+            inspected: None,
+            own_uid,
             total_memory,
+            last_update: Instant::now(),
+            cgroup_paths: HashMap::new(),
+            tick: 0,
+            cgroup_root: cgroup::unified_root(),
+            last_throttled: HashMap::new(),
+            watched: watch
+                .iter()
+                .filter_map(|spec| WatchTarget::parse(spec))
+                .map(WatchedProcess::new)
+                .collect(),
+            cpu_mode,
+            cpu_count,
         })
     }
 
     pub fn collect(&mut self) -> Result<()> {
-        self.system.refresh_processes(ProcessesToUpdate::All, true);
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::new()
+                .with_memory()
+                .with_cpu()
+                .with_disk_usage()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet)
+                .with_user(UpdateKind::OnlyIfNotSet),
+        );
+
+        // disk_usage() reports bytes since the previous refresh
+        let elapsed = self.last_update.elapsed().as_secs_f64().max(0.001);
+        self.last_update = Instant::now();
+        self.tick += 1;
 
-        let processes: Vec<_> = self.system.processes().values().collect();
+        // sysinfo lists userland threads as their own entries on Linux; they are
+        // already accounted for in their parent's thread count and CPU usage
+        let processes: Vec<_> = self.system
+            .processes()
+            .values()
+            .filter(|p| p.thread_kind() != Some(ThreadKind::Userland))
+            .collect();
 
         // Count process states
         self.data.total_processes = processes.len();
@@ -56,6 +492,9 @@ impl ProcessCollector {
         self.data.zombies = 0;
 
         let mut process_list: Vec<ProcessInfo> = Vec::with_capacity(processes.len());
+        let mut users: HashMap<String, UserUsage> = HashMap::new();
+        let mut cgroups: HashMap<String, CgroupUsage> = HashMap::new();
+        let mut cgroup_paths = HashMap::with_capacity(processes.len());
 
         for proc in &processes {
             // Count by status
@@ -66,7 +505,7 @@ impl ProcessCollector {
                 _ => {}
             }
 
-            let threads = proc.tasks().map(|t| t.len()).unwrap_or(1);
+            let threads = proc.tasks().map(|t| t.len().max(1)).unwrap_or(1);
             self.data.total_threads += threads;
 
             let memory = proc.memory();
@@ -76,14 +515,74 @@ impl ProcessCollector {
                 0.0
             };
 
+            let user = match proc.user_id() {
+                Some(uid) => self.users
+                    .get_user_by_id(uid)
+                    .map(|u| u.name().to_string())
+                    .or_else(|| uid_number(uid).map(|n| n.to_string()))
+                    .unwrap_or_default(),
+                None => String::new(),
+            };
+            let command = proc.cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let io = proc.disk_usage();
+            let uid = proc.user_id().and_then(uid_number);
+            let cpu = match self.cpu_mode {
+                CpuMode::Irix => proc.cpu_usage(),
+                CpuMode::Solaris => proc.cpu_usage() / self.cpu_count as f32,
+            };
+
+            let usage = users.entry(user.clone()).or_insert_with(|| UserUsage {
+                user: user.clone(),
+                uid,
+                ..Default::default()
+            });
+            usage.processes += 1;
+            usage.threads += threads;
+            usage.cpu_percent += cpu;
+            usage.memory_bytes += memory;
+
+            let pid = proc.pid().as_u32();
+            // A reused PID has a new start time; each PID is re-read on its own tick of the cycle
+            let key = (pid, proc.start_time());
+            let cached = self.cgroup_paths.remove(&key);
+            // disk_usage() of a process not seen at the previous refresh covers its whole
+            // life, so it has no rate yet (new() took the baseline for the first one)
+            let first_sample = cached.is_none() && self.tick > 1;
+            let reread = (self.tick + pid as u64).is_multiple_of(CGROUP_REFRESH_TICKS);
+            let cgroup_path = match cached {
+                Some(path) if !reread => Some(path),
+                cached => cgroup::read_cgroup_path(pid).or(cached),
+            }
+            .unwrap_or_else(|| "/".to_string());
+            let group = cgroups
+                .entry(cgroup_path.clone())
+                .or_insert_with(|| CgroupUsage::new(&cgroup_path));
+            group.processes += 1;
+            group.cpu_percent += cpu;
+            group.memory_bytes += memory;
+            cgroup_paths.insert(key, cgroup_path.clone());
+
             process_list.push(ProcessInfo {
-                pid: proc.pid().as_u32(),
+                pid,
+                ppid: proc.parent().map(|p| p.as_u32()),
                 name: proc.name().to_string_lossy().to_string(),
-                cpu_percent: proc.cpu_usage(),
+                command,
+                uid,
+                user,
+                cgroup: cgroup_path,
+                kernel_thread: proc.thread_kind() == Some(ThreadKind::Kernel),
+                cpu_percent: cpu,
                 memory_bytes: memory,
                 memory_percent,
+                read_rate: if first_sample { 0.0 } else { io.read_bytes as f64 / elapsed },
+                write_rate: if first_sample { 0.0 } else { io.written_bytes as f64 / elapsed },
                 threads,
                 status: format!("{:?}", proc.status()),
+                start_time: proc.start_time(),
             });
         }
 
@@ -92,11 +591,241 @@ impl ProcessCollector {
         self.data.top_cpu = process_list.iter().take(5).cloned().collect();
 
         // Sort by memory and get top 5
-        process_list.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes));
+        process_list.sort_by_key(|p| std::cmp::Reverse(p.memory_bytes));
         self.data.top_memory = process_list.iter().take(5).cloned().collect();
 
+        // Sort by disk I/O and get top 5 (only processes actually doing I/O)
+        process_list.sort_by(|a, b| b.io_rate().partial_cmp(&a.io_rate()).unwrap_or(Ordering::Equal));
+        self.data.top_io = process_list.iter().filter(|p| p.io_rate() > 0.0).take(5).cloned().collect();
+
+        self.data.processes = process_list;
+
+        let mut users: Vec<UserUsage> = users.into_values().collect();
+        users.sort_by(|a, b| {
+            b.cpu_percent
+                .partial_cmp(&a.cpu_percent)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.memory_bytes.cmp(&a.memory_bytes))
+                .then_with(|| a.user.cmp(&b.user))
+        });
+        self.data.users = users;
+
+        // Paths of exited processes are dropped here
+        self.cgroup_paths = cgroup_paths;
+        let mut cgroups: Vec<CgroupUsage> = cgroups.into_values().collect();
+        let mut last_throttled = HashMap::with_capacity(cgroups.len());
+        for group in &mut cgroups {
+            if let Some(ref root) = self.cgroup_root {
+                group.read_controller_files(root);
+            }
+            if let Some(&prev) = self.last_throttled.get(&group.path) {
+                let delta_ms = group.throttled_usec.saturating_sub(prev) as f64 / 1000.0;
+                group.throttled_ms_per_sec = delta_ms / elapsed;
+            }
+            last_throttled.insert(group.path.clone(), group.throttled_usec);
+        }
+        self.last_throttled = last_throttled;
+        cgroups.sort_by(|a, b| {
+            b.cpu_percent
+                .partial_cmp(&a.cpu_percent)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.memory_bytes.cmp(&a.memory_bytes))
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        self.data.cgroups = cgroups;
+
+        for watched in &mut self.watched {
+            watched.update(&self.data.processes);
+        }
+        self.update_inspected();
+
         Ok(())
     }
+
+    /// A CPU% on the current mode's 0-100 scale (one full core in Irix mode); used for colouring
+    pub fn cpu_share(&self, cpu: f32) -> f32 {
+        match self.cpu_mode {
+            CpuMode::Irix => cpu.min(100.0),
+            CpuMode::Solaris => cpu,
+        }
+    }
+
+    /// The unified hierarchy is mounted, so cgroup limits can be read
+    pub fn has_cgroup_v2(&self) -> bool {
+        self.cgroup_root.is_some()
+    }
+
+    /// The watch currently following `pid`, if any
+    pub fn watch_of(&self, pid: u32) -> Option<&WatchedProcess> {
+        self.watched.iter().find(|w| w.pids.contains(&pid))
+    }
+
+    /// Add a watch, or remove it if one with the same spec exists; returns true if added
+    pub fn toggle_watch(&mut self, target: WatchTarget) -> bool {
+        let spec = target.spec();
+        if let Some(pos) = self.watched.iter().position(|w| w.target.spec() == spec) {
+            self.watched.remove(pos);
+            return false;
+        }
+        let mut watched = WatchedProcess::new(target);
+        watched.update(&self.data.processes);
+        self.watched.push(watched);
+        true
+    }
+
+    /// Stop watching whatever follows `pid`; returns true if a watch was removed
+    pub fn unwatch_pid(&mut self, pid: u32) -> bool {
+        let before = self.watched.len();
+        self.watched.retain(|w| !w.pids.contains(&pid));
+        self.watched.len() != before
+    }
+
+    /// Start (or stop, with `None`) following a process in the detail pane
+    pub fn inspect(&mut self, pid: Option<u32>) {
+        self.inspected = pid.map(|pid| InspectedProcess {
+            pid,
+            details: ProcessDetails::default(),
+            cpu_history: History60::default(),
+            rss_history: History60::default(),
+            exited: false,
+        });
+        self.update_inspected();
+    }
+
+    fn update_inspected(&mut self) {
+        let Some(pid) = self.inspected.as_ref().map(|i| i.pid) else {
+            return;
+        };
+        let sample = self.data.processes
+            .iter()
+            .find(|p| p.pid == pid)
+            .map(|p| (p.cpu_percent, p.memory_bytes));
+        let details = sample.map(|_| self.read_details(pid));
+
+        if let Some(inspected) = self.inspected.as_mut() {
+            match (sample, details) {
+                (Some((cpu, rss)), Some(details)) => {
+                    inspected.cpu_history.push(cpu);
+                    inspected.rss_history.push((rss / 1024 / 1024) as f32);
+                    inspected.details = details;
+                }
+                _ => inspected.exited = true,
+            }
+        }
+    }
+
+    fn read_details(&self, pid: u32) -> ProcessDetails {
+        let mut details = ProcessDetails::default();
+
+        if let Some(proc) = self.system.process(Pid::from_u32(pid)) {
+            details.name = proc.name().to_string_lossy().to_string();
+            details.command = proc.cmd().iter().map(|a| a.to_string_lossy().to_string()).collect();
+            details.exe = proc.exe().map(|p| p.display().to_string());
+            details.start_time = proc.start_time();
+            details.uid = proc.user_id().and_then(uid_number);
+            details.user = proc.user_id()
+                .and_then(|uid| self.users.get_user_by_id(uid))
+                .map(|u| u.name().to_string())
+                .unwrap_or_default();
+            #[cfg(unix)]
+            {
+                details.gid = proc.group_id().map(|g| *g);
+            }
+        }
+
+        let dir = format!("/proc/{}", pid);
+        if let Ok(cmdline) = fs::read(format!("{}/cmdline", dir)) {
+            let args = split_nul(&cmdline);
+            if !args.is_empty() {
+                details.command = args;
+            }
+        }
+        if let Ok(environ) = fs::read(format!("{}/environ", dir)) {
+            details.environ = split_nul(&environ);
+        }
+        if let Ok(exe) = fs::read_link(format!("{}/exe", dir)) {
+            details.exe = Some(exe.display().to_string());
+        }
+        if let Ok(cwd) = fs::read_link(format!("{}/cwd", dir)) {
+            details.cwd = Some(cwd.display().to_string());
+        }
+        if let Some((nice, policy)) = read_sched(pid) {
+            details.nice = Some(nice);
+            details.policy = Some(policy);
+        }
+        if let Ok(status) = fs::read_to_string(format!("{}/status", dir)) {
+            for line in status.lines() {
+                let first_id = |l: &str| l.split_whitespace().nth(1).and_then(|v| v.parse().ok());
+                if line.starts_with("Uid:") {
+                    details.uid = first_id(line).or(details.uid);
+                } else if line.starts_with("Gid:") {
+                    details.gid = first_id(line).or(details.gid);
+                }
+            }
+        }
+        details.cgroup = cgroup::read_cgroup_path(pid);
+        details.fd_count = fs::read_dir(format!("{}/fd", dir)).ok().map(|d| d.count());
+
+        details
+    }
+}
+
+/// Split a NUL-separated /proc file (cmdline, environ)
+fn split_nul(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|&b| b == 0)
+        .filter(|s| !s.is_empty())
+        .map(|s| String::from_utf8_lossy(s).to_string())
+        .collect()
+}
+
+/// Nice value and scheduling policy from /proc/<pid>/stat
+fn read_sched(pid: u32) -> Option<(i32, &'static str)> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // The command name may contain spaces, so split after its closing paren
+    let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+    // Field numbers from proc(5), minus the pid and comm fields
+    let nice = fields.get(19 - 3)?.parse().ok()?;
+    let policy = match fields.get(41 - 3)?.parse::<u32>().ok()? {
+        0 => "SCHED_OTHER",
+        1 => "SCHED_FIFO",
+        2 => "SCHED_RR",
+        3 => "SCHED_BATCH",
+        5 => "SCHED_IDLE",
+        6 => "SCHED_DEADLINE",
+        _ => "unknown",
+    };
+    Some((nice, policy))
+}
+
+/// Numeric user ID (not available on Windows, where IDs are SIDs)
+fn uid_number(uid: &Uid) -> Option<u32> {
+    #[cfg(unix)]
+    {
+        Some(**uid)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = uid;
+        None
+    }
+}
+
+/// Format an I/O rate compactly for process tables ("12K", "3.4M")
+pub fn format_proc_rate(bytes_per_sec: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    if bytes_per_sec >= GB {
+        format!("{:.1}G", bytes_per_sec / GB)
+    } else if bytes_per_sec >= MB {
+        format!("{:.1}M", bytes_per_sec / MB)
+    } else if bytes_per_sec >= KB {
+        format!("{:.0}K", bytes_per_sec / KB)
+    } else {
+        format!("{:.0}B", bytes_per_sec)
+    }
 }
 
 /// Format memory for processes
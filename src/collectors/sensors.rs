@@ -0,0 +1,206 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::History60;
+
+const HWMON_DIR: &str = "/sys/class/hwmon";
+const THERMAL_DIR: &str = "/sys/class/thermal";
+
+/// One temperature input
+#[derive(Clone, Default)]
+pub struct SensorReading {
+    pub label: String,
+    pub celsius: f32,
+    pub max: Option<f32>,
+    pub crit: Option<f32>,
+}
+
+impl SensorReading {
+    /// Temperature as a percentage of the sensor's own limit (crit, else max, else 100°C)
+    pub fn level(&self) -> f32 {
+        let limit = self.crit.or(self.max).filter(|&l| l > 0.0).unwrap_or(100.0);
+        self.celsius / limit * 100.0
+    }
+}
+
+/// Sensors of one hwmon chip (coretemp, k10temp, nvme, acpitz, ...) or thermal zone type
+#[derive(Clone, Default)]
+pub struct SensorChip {
+    pub name: String,
+    pub sensors: Vec<SensorReading>,
+}
+
+#[derive(Clone, Default)]
+pub struct SensorData {
+    pub chips: Vec<SensorChip>,
+}
+
+pub struct SensorCollector {
+    pub data: SensorData,
+    /// Temperature history per "chip/label"
+    pub history: HashMap<String, History60>,
+}
+
+impl SensorCollector {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            data: SensorData::default(),
+            history: HashMap::new(),
+        })
+    }
+
+    pub fn collect(&mut self) -> Result<()> {
+        let mut chips = read_hwmon(Path::new(HWMON_DIR));
+        chips.extend(read_thermal_zones(Path::new(THERMAL_DIR)));
+        self.data.chips = chips;
+
+        // Update history (sensors that disappeared are dropped)
+        let mut history = HashMap::with_capacity(self.history.len());
+        for chip in &self.data.chips {
+            for sensor in &chip.sensors {
+                let key = sensor_key(&chip.name, &sensor.label);
+                let mut h = self.history.remove(&key).unwrap_or_default();
+                h.push(sensor.celsius);
+                history.insert(key, h);
+            }
+        }
+        self.history = history;
+
+        Ok(())
+    }
+
+    pub fn history_for(&self, chip: &str, label: &str) -> Option<&History60> {
+        self.history.get(&sensor_key(chip, label))
+    }
+}
+
+fn sensor_key(chip: &str, label: &str) -> String {
+    format!("{}/{}", chip, label)
+}
+
+/// /sys/class/hwmon/hwmonN: `name`, then temp<i>_input / _label / _max / _crit
+fn read_hwmon(root: &Path) -> Vec<SensorChip> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    dirs.sort_by_key(|p| trailing_number(&p.to_string_lossy()));
+
+    let mut chips: Vec<(String, String, Vec<SensorReading>)> = Vec::new();
+    for dir in dirs {
+        let Some(name) = read_trimmed(&dir.join("name")) else { continue };
+        let mut inputs: Vec<u32> = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| {
+                let file = e.file_name().to_string_lossy().to_string();
+                file.strip_prefix("temp")?.strip_suffix("_input")?.parse().ok()
+            })
+            .collect();
+        inputs.sort_unstable();
+
+        let sensors: Vec<SensorReading> = inputs
+            .into_iter()
+            .filter_map(|i| {
+                let file = |suffix: &str| dir.join(format!("temp{}_{}", i, suffix));
+                Some(SensorReading {
+                    label: read_trimmed(&file("label")).unwrap_or_else(|| format!("temp{}", i)),
+                    celsius: read_millidegrees(&file("input"))?,
+                    max: read_millidegrees(&file("max")),
+                    crit: read_millidegrees(&file("crit")),
+                })
+            })
+            .collect();
+        if sensors.is_empty() {
+            continue;
+        }
+        // Tells apart chips sharing a name, e.g. nvme0 / nvme1
+        let device = fs::read_link(dir.join("device"))
+            .ok()
+            .and_then(|p| p.file_name().map(|f| f.to_string_lossy().to_string()))
+            .unwrap_or_else(|| dir.file_name().map(|f| f.to_string_lossy().to_string()).unwrap_or_default());
+        chips.push((name, device, sensors));
+    }
+
+    let mut result: Vec<SensorChip> = chips
+        .iter()
+        .map(|(name, device, sensors)| {
+            let shared = chips.iter().filter(|(other, _, _)| other == name).count() > 1;
+            SensorChip {
+                name: if shared { format!("{} {}", name, device) } else { name.clone() },
+                sensors: sensors.clone(),
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+/// Thermal zones not already exposed through hwmon, grouped by zone type
+fn read_thermal_zones(root: &Path) -> Vec<SensorChip> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut zones: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.file_name().is_some_and(|f| f.to_string_lossy().starts_with("thermal_zone")))
+        .collect();
+    zones.sort_by_key(|p| trailing_number(&p.to_string_lossy()));
+
+    let mut chips: Vec<SensorChip> = Vec::new();
+    for zone in zones {
+        // A zone with an hwmon child already shows up in read_hwmon
+        let has_hwmon = fs::read_dir(&zone)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|e| e.file_name().to_string_lossy().starts_with("hwmon"));
+        if has_hwmon {
+            continue;
+        }
+        let Some(kind) = read_trimmed(&zone.join("type")) else { continue };
+        let Some(celsius) = read_millidegrees(&zone.join("temp")) else { continue };
+
+        let mut sensor = SensorReading {
+            label: zone.file_name().map(|f| f.to_string_lossy().to_string()).unwrap_or_default(),
+            celsius,
+            max: None,
+            crit: None,
+        };
+        for trip in 0.. {
+            let Some(trip_type) = read_trimmed(&zone.join(format!("trip_point_{}_type", trip))) else { break };
+            let temp = read_millidegrees(&zone.join(format!("trip_point_{}_temp", trip)));
+            match trip_type.as_str() {
+                "critical" => sensor.crit = temp,
+                "hot" | "passive" if sensor.max.is_none() => sensor.max = temp,
+                _ => {}
+            }
+        }
+
+        match chips.iter_mut().find(|c| c.name == kind) {
+            Some(chip) => chip.sensors.push(sensor),
+            None => chips.push(SensorChip { name: kind, sensors: vec![sensor] }),
+        }
+    }
+    chips
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// sysfs temperatures are in millidegrees Celsius
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    read_trimmed(path)?.parse::<i64>().ok().map(|m| m as f32 / 1000.0)
+}
+
+/// hwmon10 sorts after hwmon9
+fn trailing_number(name: &str) -> u32 {
+    let digits: String = name.chars().rev().take_while(|c| c.is_ascii_digit()).collect();
+    digits.chars().rev().collect::<String>().parse().unwrap_or(0)
+}
@@ -19,8 +19,8 @@ impl SystemCollector {
     pub fn new() -> Result<Self> {
         let data = SystemData {
             os_name: System::name().unwrap_or_else(|| "Unknown".to_string()),
-            os_version: System::os_version().unwrap_or_else(|| "".to_string()),
-            kernel_version: System::kernel_version().unwrap_or_else(|| "".to_string()),
+            os_version: System::os_version().unwrap_or_default(),
+            kernel_version: System::kernel_version().unwrap_or_default(),
             hostname: System::host_name().unwrap_or_else(|| "localhost".to_string()),
             uptime_secs: System::uptime(),
         };
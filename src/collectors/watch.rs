@@ -0,0 +1,154 @@
+use chrono::{DateTime, Local};
+use regex::Regex;
+
+use super::History60;
+use super::process::ProcessInfo;
+
+/// What a watch follows: one PID, or every process whose name matches a pattern
+#[derive(Clone, Debug)]
+pub enum WatchTarget {
+    Pid(u32),
+    /// `*` matches any run of characters; otherwise the whole name must match
+    Name { pattern: String, regex: Regex },
+}
+
+impl WatchTarget {
+    /// A number pins a PID, anything else is a name pattern
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        if let Ok(pid) = input.parse() {
+            return Some(WatchTarget::Pid(pid));
+        }
+        let glob = input.split('*').map(regex::escape).collect::<Vec<_>>().join(".*");
+        let regex = Regex::new(&format!("^{}$", glob)).ok()?;
+        Some(WatchTarget::Name { pattern: input.to_string(), regex })
+    }
+
+    /// The text this target was parsed from (what gets saved to the config)
+    pub fn spec(&self) -> String {
+        match self {
+            WatchTarget::Pid(pid) => pid.to_string(),
+            WatchTarget::Name { pattern, .. } => pattern.clone(),
+        }
+    }
+}
+
+/// Last lifecycle change of a watched process
+#[derive(Clone, Debug)]
+pub enum WatchEvent {
+    Exited { pid: u32, at: DateTime<Local> },
+    Restarted { old_pid: u32, new_pid: u32, at: DateTime<Local> },
+}
+
+/// A watched PID or name pattern and its history across refreshes
+pub struct WatchedProcess {
+    pub target: WatchTarget,
+    /// Name of the process last seen for this target
+    pub name: String,
+    /// Currently matching PIDs (a name pattern can match several)
+    pub pids: Vec<u32>,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub io_rate: f64,
+    pub cpu_history: History60,
+    /// RSS in MB
+    pub rss_history: History60,
+    /// Read + write in KB/s
+    pub io_history: History60,
+    pub event: Option<WatchEvent>,
+    last_pid: Option<u32>,
+    /// PID a pinned watch currently follows (differs from the target after a restart)
+    following: Option<u32>,
+    /// The followed process as last seen, and when (seconds since the epoch)
+    last_seen: Option<(ProcessInfo, u64)>,
+}
+
+impl WatchedProcess {
+    pub fn new(target: WatchTarget) -> Self {
+        Self {
+            target,
+            name: String::new(),
+            pids: Vec::new(),
+            cpu_percent: 0.0,
+            memory_bytes: 0,
+            io_rate: 0.0,
+            cpu_history: History60::default(),
+            rss_history: History60::default(),
+            io_history: History60::default(),
+            event: None,
+            last_pid: None,
+            following: None,
+            last_seen: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.pids.is_empty()
+    }
+
+    /// Processes currently matching the target
+    fn matching<'a>(&mut self, processes: &'a [ProcessInfo]) -> Vec<&'a ProcessInfo> {
+        let pid = match self.target {
+            WatchTarget::Name { ref regex, .. } => {
+                return processes.iter().filter(|p| regex.is_match(&p.name)).collect();
+            }
+            WatchTarget::Pid(pid) => self.following.unwrap_or(pid),
+        };
+        if let Some(p) = processes.iter().find(|p| p.pid == pid) {
+            return vec![p];
+        }
+        // A pinned PID that went away is only followed to a likely restart of it:
+        // same name and parent or (known) cgroup, started after it was last seen alive
+        let Some((last, seen_at)) = &self.last_seen else {
+            return Vec::new();
+        };
+        let restart = processes.iter().find(|p| {
+            p.name == last.name
+                && (p.ppid == last.ppid || (p.cgroup == last.cgroup && last.cgroup != "/"))
+                && p.start_time >= *seen_at
+        });
+        if let Some(p) = restart {
+            self.following = Some(p.pid);
+        }
+        restart.into_iter().collect()
+    }
+
+    /// Match against the latest process list and record one history sample
+    pub fn update(&mut self, processes: &[ProcessInfo]) {
+        let matched = self.matching(processes);
+
+        if matched.is_empty() {
+            if let Some(&pid) = self.pids.first() {
+                self.event = Some(WatchEvent::Exited { pid, at: Local::now() });
+            }
+            self.pids.clear();
+            self.cpu_percent = 0.0;
+            self.memory_bytes = 0;
+            self.io_rate = 0.0;
+        } else {
+            let pids: Vec<u32> = matched.iter().map(|p| p.pid).collect();
+            // Restarted: none of the previous PIDs survived (or it had exited)
+            if let Some(old_pid) = self.last_pid {
+                if !self.pids.iter().any(|pid| pids.contains(pid)) && !pids.contains(&old_pid) {
+                    self.event = Some(WatchEvent::Restarted { old_pid, new_pid: pids[0], at: Local::now() });
+                }
+            }
+            if matches!(self.target, WatchTarget::Pid(_)) {
+                self.last_seen = Some((matched[0].clone(), Local::now().timestamp() as u64));
+            }
+            self.name = matched[0].name.clone();
+            self.cpu_percent = matched.iter().map(|p| p.cpu_percent).sum();
+            self.memory_bytes = matched.iter().map(|p| p.memory_bytes).sum();
+            self.io_rate = matched.iter().map(|p| p.io_rate()).sum();
+            self.last_pid = Some(pids[0]);
+            self.pids = pids;
+        }
+
+        self.cpu_history.push(self.cpu_percent);
+        self.rss_history.push((self.memory_bytes / 1024 / 1024) as f32);
+        self.io_history.push((self.io_rate / 1024.0) as f32);
+    }
+}
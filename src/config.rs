@@ -2,6 +2,33 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+/// How process CPU% is scaled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CpuMode {
+    /// 100% = one core, so busy multi-threaded processes exceed 100%
+    #[default]
+    Irix,
+    /// 100% = every core busy
+    Solaris,
+}
+
+impl CpuMode {
+    pub fn toggle(self) -> Self {
+        match self {
+            CpuMode::Irix => CpuMode::Solaris,
+            CpuMode::Solaris => CpuMode::Irix,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CpuMode::Irix => "Irix (per core)",
+            CpuMode::Solaris => "Solaris (whole machine)",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default = "default_refresh_rate")]
@@ -21,6 +48,30 @@ pub struct Config {
 
     #[serde(default = "default_graph_history")]
     pub graph_history: usize,
+
+    /// Process CPU% scale: "irix" (per core) or "solaris" (whole machine)
+    #[serde(default)]
+    pub cpu_mode: CpuMode,
+
+    /// Watched processes: PIDs or name patterns (`*` wildcard)
+    #[serde(default)]
+    pub watch: Vec<String>,
+
+    /// Watches from `--watch`, for this session only (never saved)
+    #[serde(skip)]
+    pub cli_watch: Vec<String>,
+
+    /// Filesystems left out of the disks view: fs types or mount points (`*` wildcard)
+    #[serde(default = "default_filesystem_exclude")]
+    pub filesystem_exclude: Vec<String>,
+
+    /// Filesystems listed even when an exclude pattern matches them
+    #[serde(default)]
+    pub filesystem_include: Vec<String>,
+
+    /// Mount point shown in the dashboard disk panel ("/" or the largest when unset)
+    #[serde(default)]
+    pub disk_mount: Option<String>,
 }
 
 fn default_refresh_rate() -> f64 {
@@ -39,6 +90,20 @@ fn default_graph_history() -> usize {
     60
 }
 
+/// Kernel pseudo filesystems and container layers
+fn default_filesystem_exclude() -> Vec<String> {
+    [
+        "proc", "sysfs", "devtmpfs", "devpts", "cgroup", "cgroup2", "securityfs", "pstore",
+        "debugfs", "tracefs", "configfs", "fusectl", "mqueue", "hugetlbfs", "bpf", "binfmt_misc",
+        "autofs", "efivarfs", "rpc_pipefs", "nsfs", "selinuxfs", "ramfs",
+        "/proc/*", "/sys/*", "/var/lib/docker/*", "/var/lib/containers/*", "/run/containerd/*",
+        "/run/netns/*", "/snap/*",
+    ]
+    .iter()
+    .map(|p| p.to_string())
+    .collect()
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -48,6 +113,12 @@ impl Default for Config {
             compact_mode: false,
             show_graphs: true,
             graph_history: default_graph_history(),
+            cpu_mode: CpuMode::default(),
+            watch: Vec::new(),
+            cli_watch: Vec::new(),
+            filesystem_exclude: default_filesystem_exclude(),
+            filesystem_include: Vec::new(),
+            disk_mount: None,
         }
     }
 }
@@ -97,6 +168,27 @@ impl Config {
         self
     }
 
+    pub fn with_watch(mut self, watch: &[String]) -> Self {
+        self.cli_watch.extend(watch.iter().cloned());
+        self
+    }
+
+    /// Saved watches followed by the session's `--watch` ones, without duplicates
+    pub fn watch_targets(&self) -> Vec<String> {
+        let mut targets: Vec<String> = Vec::new();
+        for spec in self.watch.iter().chain(&self.cli_watch) {
+            if !targets.contains(spec) {
+                targets.push(spec.clone());
+            }
+        }
+        targets
+    }
+
+    pub fn with_disk_mount(mut self, mount_point: &str) -> Self {
+        self.disk_mount = Some(mount_point.to_string());
+        self
+    }
+
     /// Save config to default location
     pub fn save(&self) -> Result<()> {
         if let Some(config_dir) = dirs::config_dir() {
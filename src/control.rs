@@ -0,0 +1,323 @@
+use std::io;
+
+use crate::error::MonitorError;
+
+/// Signals offered in the process view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Signal {
+    Term,
+    Kill,
+    Stop,
+    Cont,
+    Hup,
+    Int,
+    Usr1,
+    Usr2,
+}
+
+impl Signal {
+    pub const ALL: [Signal; 8] = [
+        Signal::Term,
+        Signal::Kill,
+        Signal::Stop,
+        Signal::Cont,
+        Signal::Hup,
+        Signal::Int,
+        Signal::Usr1,
+        Signal::Usr2,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Signal::Term => "SIGTERM",
+            Signal::Kill => "SIGKILL",
+            Signal::Stop => "SIGSTOP",
+            Signal::Cont => "SIGCONT",
+            Signal::Hup => "SIGHUP",
+            Signal::Int => "SIGINT",
+            Signal::Usr1 => "SIGUSR1",
+            Signal::Usr2 => "SIGUSR2",
+        }
+    }
+
+    #[cfg(unix)]
+    fn raw(self) -> libc::c_int {
+        match self {
+            Signal::Term => libc::SIGTERM,
+            Signal::Kill => libc::SIGKILL,
+            Signal::Stop => libc::SIGSTOP,
+            Signal::Cont => libc::SIGCONT,
+            Signal::Hup => libc::SIGHUP,
+            Signal::Int => libc::SIGINT,
+            Signal::Usr1 => libc::SIGUSR1,
+            Signal::Usr2 => libc::SIGUSR2,
+        }
+    }
+}
+
+/// Linux I/O scheduling classes (see ioprio_set(2))
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IoClass {
+    Realtime,
+    BestEffort,
+    Idle,
+}
+
+impl IoClass {
+    /// Parse "rt:0", "be:4", "be" or "idle"; level defaults to 4 and is always 0 for idle
+    pub fn parse(input: &str) -> Option<(IoClass, u8)> {
+        let input = input.trim().to_lowercase();
+        let (class, level) = match input.split_once(':') {
+            Some((c, l)) => (c.trim().to_string(), Some(l.trim().parse::<u8>().ok()?)),
+            None => (input, None),
+        };
+        let class = match class.as_str() {
+            "rt" | "realtime" | "1" => IoClass::Realtime,
+            "be" | "best-effort" | "2" => IoClass::BestEffort,
+            "idle" | "3" => IoClass::Idle,
+            _ => return None,
+        };
+        let level = if class == IoClass::Idle { 0 } else { level.unwrap_or(4) };
+        if level > 7 {
+            return None;
+        }
+        Some((class, level))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            IoClass::Realtime => "realtime",
+            IoClass::BestEffort => "best-effort",
+            IoClass::Idle => "idle",
+        }
+    }
+}
+
+fn control_error(action: &'static str, pid: u32, source: io::Error) -> MonitorError {
+    MonitorError::ProcessControl { action, pid, source }
+}
+
+#[allow(dead_code)]
+fn unsupported(action: &'static str, pid: u32) -> MonitorError {
+    control_error(action, pid, io::Error::from(io::ErrorKind::Unsupported))
+}
+
+/// Send a signal to a single process
+pub fn send_signal(pid: u32, signal: Signal) -> Result<(), MonitorError> {
+    #[cfg(unix)]
+    {
+        // SAFETY: kill(2) has no memory-safety preconditions
+        if unsafe { libc::kill(pid as libc::pid_t, signal.raw()) } == 0 {
+            Ok(())
+        } else {
+            Err(control_error(signal.name(), pid, io::Error::last_os_error()))
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        Err(unsupported(signal.name(), pid))
+    }
+}
+
+/// Set the nice value (-20..=19) of a process
+pub fn set_nice(pid: u32, nice: i32) -> Result<(), MonitorError> {
+    #[cfg(unix)]
+    {
+        // SAFETY: setpriority(2) has no memory-safety preconditions
+        if unsafe { libc::setpriority(libc::PRIO_PROCESS as _, pid as libc::id_t, nice) } == 0 {
+            Ok(())
+        } else {
+            Err(control_error("renice", pid, io::Error::last_os_error()))
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = nice;
+        Err(unsupported("renice", pid))
+    }
+}
+
+/// Read the current nice value of a process
+pub fn get_nice(pid: u32) -> Option<i32> {
+    #[cfg(target_os = "linux")]
+    {
+        // getpriority can legitimately return -1, so errno has to be checked
+        // SAFETY: errno location is thread-local and always valid
+        unsafe {
+            *libc::__errno_location() = 0;
+            let nice = libc::getpriority(libc::PRIO_PROCESS as _, pid as libc::id_t);
+            if nice == -1 && *libc::__errno_location() != 0 {
+                None
+            } else {
+                Some(nice)
+            }
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Set the I/O scheduling class and level of a process
+pub fn set_ionice(pid: u32, class: IoClass, level: u8) -> Result<(), MonitorError> {
+    #[cfg(target_os = "linux")]
+    {
+        const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+        const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+        let class_id = match class {
+            IoClass::Realtime => 1,
+            IoClass::BestEffort => 2,
+            IoClass::Idle => 3,
+        };
+        let ioprio = (class_id << IOPRIO_CLASS_SHIFT) | level as libc::c_int;
+        // SAFETY: ioprio_set takes only integer arguments
+        let ret = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid as libc::c_int, ioprio) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(control_error("ionice", pid, io::Error::last_os_error()))
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (class, level);
+        Err(unsupported("ionice", pid))
+    }
+}
+
+/// Restrict a process to the given logical CPUs
+pub fn set_affinity(pid: u32, cpus: &[usize]) -> Result<(), MonitorError> {
+    #[cfg(target_os = "linux")]
+    {
+        // CPU_SET indexes the mask without a bounds check
+        if cpus.iter().any(|&cpu| cpu >= libc::CPU_SETSIZE as usize) {
+            return Err(control_error("set affinity", pid, io::Error::from(io::ErrorKind::InvalidInput)));
+        }
+        // SAFETY: cpu_set_t is plain data and every index is below CPU_SETSIZE (checked above)
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            for &cpu in cpus {
+                libc::CPU_SET(cpu, &mut set);
+            }
+            let size = std::mem::size_of::<libc::cpu_set_t>();
+            if libc::sched_setaffinity(pid as libc::pid_t, size, &set) == 0 {
+                Ok(())
+            } else {
+                Err(control_error("set affinity", pid, io::Error::last_os_error()))
+            }
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = cpus;
+        Err(unsupported("set affinity", pid))
+    }
+}
+
+/// Logical CPUs a process is allowed to run on
+pub fn get_affinity(pid: u32) -> Option<Vec<usize>> {
+    #[cfg(target_os = "linux")]
+    {
+        // SAFETY: cpu_set_t is plain data and sized correctly for the call
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            let size = std::mem::size_of::<libc::cpu_set_t>();
+            if libc::sched_getaffinity(pid as libc::pid_t, size, &mut set) != 0 {
+                return None;
+            }
+            let max = libc::CPU_SETSIZE as usize;
+            Some((0..max).filter(|&cpu| libc::CPU_ISSET(cpu, &set)).collect())
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// One entry of a CPU list: "N", "N-M" or "N-M:used/group" (the first `used` CPUs of every `group`)
+#[derive(Clone, Copy, Debug)]
+pub struct CpuRange {
+    pub start: usize,
+    pub end: usize,
+    used: usize,
+    group: usize,
+}
+
+impl CpuRange {
+    pub fn cpus(self) -> impl Iterator<Item = usize> {
+        (self.start..=self.end).filter(move |cpu| (cpu - self.start) % self.group < self.used)
+    }
+}
+
+/// Parse the kernel's cpulist format ("0-3,6,8-11:2/4"), as in sysfs and taskset; None if malformed.
+/// Ranges are not expanded, so callers can bound them first.
+pub fn parse_cpu_ranges(input: &str) -> Option<Vec<CpuRange>> {
+    let mut ranges = Vec::new();
+    for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (span, stride) = match part.split_once(':') {
+            Some((span, stride)) => (span, Some(stride)),
+            None => (part, None),
+        };
+        let (start, end) = match span.split_once('-') {
+            Some((start, end)) => (start.trim().parse().ok()?, end.trim().parse().ok()?),
+            None => {
+                let cpu: usize = span.trim().parse().ok()?;
+                (cpu, cpu)
+            }
+        };
+        let (used, group) = match stride {
+            Some(stride) => {
+                let (used, group) = stride.split_once('/')?;
+                (used.trim().parse().ok()?, group.trim().parse().ok()?)
+            }
+            None => (1, 1),
+        };
+        if start > end || used == 0 || used > group {
+            return None;
+        }
+        ranges.push(CpuRange { start, end, used, group });
+    }
+    Some(ranges)
+}
+
+/// Parse a CPU list like "0-3,6,8-9"; None if it names a CPU at or above `cpu_count`
+pub fn parse_cpu_list(input: &str, cpu_count: usize) -> Option<Vec<usize>> {
+    let ranges = parse_cpu_ranges(input)?;
+    // Checked before expanding, so "0-99999999999" cannot allocate a huge list
+    if ranges.iter().any(|r| r.end >= cpu_count) {
+        return None;
+    }
+    let mut cpus: Vec<usize> = ranges.into_iter().flat_map(CpuRange::cpus).collect();
+    cpus.sort_unstable();
+    cpus.dedup();
+    if cpus.is_empty() {
+        None
+    } else {
+        Some(cpus)
+    }
+}
+
+/// Format CPUs as a compact list ("0-3,6")
+pub fn format_cpu_list(cpus: &[usize]) -> String {
+    let mut parts = Vec::new();
+    let mut i = 0;
+    while i < cpus.len() {
+        let start = cpus[i];
+        let mut end = start;
+        while i + 1 < cpus.len() && cpus[i + 1] == end + 1 {
+            i += 1;
+            end = cpus[i];
+        }
+        if start == end {
+            parts.push(start.to_string());
+        } else {
+            parts.push(format!("{}-{}", start, end));
+        }
+        i += 1;
+    }
+    parts.join(",")
+}
@@ -18,6 +18,14 @@ pub enum MonitorError {
     #[error("Terminal error: {0}")]
     Terminal(String),
 
+    #[error("{action} PID {pid}: {source}")]
+    ProcessControl {
+        action: &'static str,
+        pid: u32,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
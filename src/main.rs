@@ -2,6 +2,7 @@ mod app;
 mod config;
 mod error;
 mod collectors;
+mod control;
 mod ui;
 mod themes;
 
@@ -9,13 +10,13 @@ use std::io;
 use anyhow::Result;
 use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
 
-use crate::app::App;
+use crate::app::{App, Dialog, InputKind, View};
 use crate::config::Config;
 
 #[derive(Parser, Debug)]
@@ -40,6 +41,14 @@ struct Args {
     #[arg(short, long)]
     compact: bool,
 
+    /// Watch a process by PID or name pattern (`*` wildcard); repeatable
+    #[arg(short, long, value_name = "PID|NAME")]
+    watch: Vec<String>,
+
+    /// Filesystem shown in the dashboard disk panel, by mount point
+    #[arg(long, value_name = "MOUNT")]
+    disk: Option<String>,
+
     /// Path to config file
     #[arg(short = 'C', long)]
     config: Option<String>,
@@ -99,7 +108,7 @@ fn main() -> Result<()> {
         println!("╠══════════════════════════════════════════╣");
         println!("║ GPU                                      ║");
         if collectors.gpu.data.available {
-            println!("║   ✅ {}  ║", format!("{:<30}", collectors.gpu.data.name));
+            println!("║   ✅ {:<30}  ║", collectors.gpu.data.name);
             println!("║   Driver: {:>30} ║", collectors.gpu.data.driver_version);
         } else {
             println!("║   ❌ No NVIDIA GPU detected              ║");
@@ -131,6 +140,12 @@ fn main() -> Result<()> {
     if args.compact {
         config = config.with_compact(true);
     }
+    if !args.watch.is_empty() {
+        config = config.with_watch(&args.watch);
+    }
+    if let Some(ref mount_point) = args.disk {
+        config = config.with_disk_mount(mount_point);
+    }
 
     // Setup terminal
     enable_raw_mode()?;
@@ -174,8 +189,14 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
         // Poll for events with timeout
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press && app.dialog.is_some() => {
+                    handle_dialog_key(app, key.code);
+                }
+                Event::Key(key) if key.kind == KeyEventKind::Press && app.process_view.filter_editing => {
+                    handle_filter_key(app, key.code);
+                }
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Char('Q') => {
                             app.should_quit = true;
@@ -198,16 +219,55 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
                         KeyCode::Char('s') | KeyCode::Char('S') => {
                             app.save_config();
                         }
+                        KeyCode::Char('c') | KeyCode::Char('C') => {
+                            app.toggle_cpu_mode();
+                        }
+                        KeyCode::Tab => {
+                            app.next_view();
+                        }
+                        KeyCode::BackTab => {
+                            app.prev_view();
+                        }
+                        KeyCode::Char(c @ '1'..='9') => {
+                            if let Some(&view) = View::ALL.get(c as usize - '1' as usize) {
+                                app.view = view;
+                            }
+                        }
                         KeyCode::Esc => {
                             if app.show_help {
                                 app.show_help = false;
+                            } else if app.view == View::Processes && app.collectors.process.inspected.is_some() {
+                                app.toggle_details();
+                            } else if app.view == View::Processes && !app.process_view.filter_input.is_empty() {
+                                app.finish_filter(true);
                             } else {
                                 app.should_quit = true;
                             }
                         }
+                        code if app.view == View::Processes => {
+                            handle_process_key(app, code);
+                        }
+                        code if matches!(app.view, View::Users | View::Services | View::Interrupts | View::Disks) => {
+                            handle_list_key(app, code);
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Mouse(mouse) if app.view == View::Processes => {
+                    match mouse.kind {
+                        MouseEventKind::ScrollDown => app.move_selection(3),
+                        MouseEventKind::ScrollUp => app.move_selection(-3),
                         _ => {}
                     }
                 }
+                Event::Mouse(mouse) => {
+                    match mouse.kind {
+                        MouseEventKind::ScrollDown => app.move_list_selection(3),
+                        MouseEventKind::ScrollUp => app.move_list_selection(-3),
+                        _ => {}
+                    }
+                }
+                _ => {}
             }
         }
 
@@ -226,3 +286,78 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
         app.clear_expired_status();
     }
 }
+
+/// Keys that only apply to the process table
+fn handle_process_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Down => app.move_selection(1),
+        KeyCode::Up => app.move_selection(-1),
+        KeyCode::PageDown => app.page_selection(true),
+        KeyCode::PageUp => app.page_selection(false),
+        KeyCode::Home => app.select_edge(false),
+        KeyCode::End => app.select_edge(true),
+        KeyCode::Char('>') | KeyCode::Char('.') => app.cycle_sort_column(true),
+        KeyCode::Char('<') | KeyCode::Char(',') => app.cycle_sort_column(false),
+        KeyCode::Char('i') | KeyCode::Char('I') => app.toggle_sort_order(),
+        KeyCode::F(5) => app.toggle_tree_mode(),
+        KeyCode::Char('k') => app.open_signal_menu(false),
+        KeyCode::Char('K') => app.open_signal_menu(true),
+        KeyCode::Char('n') | KeyCode::Char('N') => app.open_input(InputKind::Nice),
+        KeyCode::Char('o') | KeyCode::Char('O') => app.open_input(InputKind::IoNice),
+        KeyCode::Char('a') | KeyCode::Char('A') => app.open_input(InputKind::Affinity),
+        KeyCode::Char('/') => app.start_filter(),
+        KeyCode::Enter => app.toggle_details(),
+        KeyCode::Char('x') | KeyCode::Char('X') => app.toggle_kernel_threads(),
+        KeyCode::Char('u') | KeyCode::Char('U') => app.toggle_own_user(),
+        KeyCode::Char('w') => app.toggle_watch(false),
+        KeyCode::Char('W') => app.toggle_watch(true),
+        KeyCode::Char(' ') => app.set_selected_collapsed(None),
+        KeyCode::Left => app.set_selected_collapsed(Some(true)),
+        KeyCode::Right => app.set_selected_collapsed(Some(false)),
+        _ => {}
+    }
+}
+
+/// Keys for the grouped list views (users, ...)
+fn handle_list_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Down => app.move_list_selection(1),
+        KeyCode::Up => app.move_list_selection(-1),
+        KeyCode::PageDown => app.page_list_selection(true),
+        KeyCode::PageUp => app.page_list_selection(false),
+        KeyCode::Home => app.move_list_selection(isize::MIN),
+        KeyCode::End => app.move_list_selection(isize::MAX),
+        KeyCode::Enter => app.drill_down(),
+        _ => {}
+    }
+}
+
+/// Keys while a dialog is open
+fn handle_dialog_key(app: &mut App, code: KeyCode) {
+    let is_input = matches!(app.dialog, Some(Dialog::Input { .. }));
+    let is_confirm = matches!(app.dialog, Some(Dialog::ConfirmSignal { .. }));
+    match code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter => app.dialog_confirm(),
+        KeyCode::Up => app.dialog_move(-1),
+        KeyCode::Down => app.dialog_move(1),
+        KeyCode::Backspace if is_input => app.dialog_backspace(),
+        KeyCode::Char(c) if is_input => app.dialog_char(c),
+        KeyCode::Char('y') | KeyCode::Char('Y') if is_confirm => app.dialog_confirm(),
+        KeyCode::Char('n') | KeyCode::Char('N') if is_confirm => app.close_dialog(),
+        _ => {}
+    }
+}
+
+/// Keys while typing in the `/` search prompt
+fn handle_filter_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Enter => app.finish_filter(false),
+        KeyCode::Esc => app.finish_filter(true),
+        KeyCode::Backspace => app.filter_char(None),
+        KeyCode::Char(c) => app.filter_char(Some(c)),
+        KeyCode::Down => app.move_selection(1),
+        KeyCode::Up => app.move_selection(-1),
+        _ => {}
+    }
+}
@@ -0,0 +1,61 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Color,
+    widgets::Widget,
+};
+
+/// One-line gauge made of several coloured segments laid end to end
+pub struct StackedGauge<'a> {
+    /// (percent of the whole bar, colour), drawn left to right
+    segments: &'a [(f32, Color)],
+    empty: Color,
+    label: String,
+}
+
+impl<'a> StackedGauge<'a> {
+    pub fn new(segments: &'a [(f32, Color)], empty: Color) -> Self {
+        Self { segments, empty, label: String::new() }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+}
+
+impl Widget for StackedGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let width = area.width as f32;
+        let y = area.y + area.height / 2;
+
+        // Segment ends come from the running total so rounding never drifts
+        let mut colors = vec![None; area.width as usize];
+        let mut total = 0.0;
+        let mut start = 0;
+        for &(percent, color) in self.segments {
+            total += percent.max(0.0);
+            let end = ((total / 100.0 * width).round() as usize).min(colors.len());
+            for slot in colors.iter_mut().take(end).skip(start) {
+                *slot = Some(color);
+            }
+            start = start.max(end);
+        }
+
+        let label: Vec<char> = self.label.chars().take(area.width as usize).collect();
+        let label_start = (area.width as usize - label.len()) / 2;
+        for (i, color) in colors.iter().enumerate() {
+            let cell = &mut buf[(area.x + i as u16, y)];
+            let ch = i.checked_sub(label_start).and_then(|j| label.get(j)).copied().unwrap_or(' ');
+            cell.set_char(ch);
+            match color {
+                // Text over a filled segment takes the empty colour, like ratatui's Gauge
+                Some(color) => cell.set_bg(*color).set_fg(self.empty),
+                None => cell.set_bg(self.empty).set_fg(Color::Reset),
+            };
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Color,
+    widgets::Widget,
+};
+
+use crate::themes::Theme;
+
+/// Shades for one-row-per-series mode, by quarter of the value range
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Series (e.g. CPU cores) down, time across with the newest sample on the right
+///
+/// With fewer rows than series, each row packs two series in a half block:
+/// the upper one as foreground, the lower one as background.
+pub struct Heatmap<'a> {
+    /// One history per series, values 0-100
+    series: &'a [Vec<u64>],
+    theme: &'a Theme,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new(series: &'a [Vec<u64>], theme: &'a Theme) -> Self {
+        Self { series, theme }
+    }
+
+    /// Near-idle cells fade into the empty bar colour
+    fn color(&self, value: u64) -> Color {
+        if value < 5 {
+            self.theme.bar_empty
+        } else {
+            self.theme.usage_color(value as f32)
+        }
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 || self.series.is_empty() {
+            return;
+        }
+        let packed = self.series.len() > area.height as usize;
+        let per_row = if packed { 2 } else { 1 };
+        let width = area.width as usize;
+
+        for (row, chunk) in self.series.chunks(per_row).take(area.height as usize).enumerate() {
+            let y = area.y + row as u16;
+            for col in 0..width {
+                // Column `col` shows the sample `width - col` refreshes old
+                let sample = |data: &Vec<u64>| data.len().checked_sub(width - col).map(|i| data[i]);
+                let x = area.x + col as u16;
+                let cell = &mut buf[(x, y)];
+                if packed {
+                    let top = chunk.first().and_then(sample);
+                    let bottom = chunk.get(1).and_then(sample);
+                    if top.is_none() && bottom.is_none() {
+                        continue;
+                    }
+                    // An odd series count, or an empty padding series, leaves the lower half empty
+                    let bg = match chunk.get(1).filter(|s| !s.is_empty()) {
+                        Some(_) => self.color(bottom.unwrap_or(0)),
+                        None => Color::Reset,
+                    };
+                    cell.set_char('▀').set_fg(self.color(top.unwrap_or(0))).set_bg(bg);
+                } else if let Some(value) = sample(&chunk[0]) {
+                    let shade = ((value.min(100) as usize * 4).div_ceil(100)).min(4);
+                    cell.set_char(SHADES[shade]).set_fg(self.theme.usage_color(value as f32));
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 // Reusable UI components will be added here
 // - progress_bar.rs
-// - sparkline.rs (custom)
-// - gauge.rs
-// - heatmap.rs
+pub mod gauge;
+pub mod heatmap;
+pub mod sparkline;
@@ -0,0 +1,70 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Color,
+    widgets::Widget,
+};
+
+const BARS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// Sparkline whose columns stack several series on top of each other
+///
+/// A terminal cell holds one colour, so each cell takes the colour of the
+/// series covering most of it; with a one-row area that is the dominant series.
+pub struct StackedSparkline<'a> {
+    /// (history, colour), bottom series first; histories are aligned on their newest sample
+    series: &'a [(Vec<u64>, Color)],
+    max: u64,
+}
+
+impl<'a> StackedSparkline<'a> {
+    pub fn new(series: &'a [(Vec<u64>, Color)], max: u64) -> Self {
+        Self { series, max: max.max(1) }
+    }
+}
+
+impl Widget for StackedSparkline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let len = self.series.iter().map(|(data, _)| data.len()).max().unwrap_or(0);
+        if area.width == 0 || area.height == 0 || len == 0 {
+            return;
+        }
+        let columns = len.min(area.width as usize);
+        let eighths = area.height as u64 * 8;
+
+        for col in 0..columns {
+            let sample = len - columns + col;
+            // Cumulative top of each series, in eighths of a row
+            let mut tops = Vec::with_capacity(self.series.len());
+            let mut sum = 0;
+            for (data, color) in self.series {
+                let offset = len - data.len();
+                sum += sample.checked_sub(offset).and_then(|i| data.get(i)).copied().unwrap_or(0);
+                tops.push(((sum.min(self.max) * eighths + self.max / 2) / self.max, *color));
+            }
+            let height = tops.last().map(|&(top, _)| top).unwrap_or(0);
+
+            for row in 0..area.height as u64 {
+                let (low, high) = (row * 8, row * 8 + 8);
+                if height <= low {
+                    break;
+                }
+                let mut bottom = 0;
+                let mut best = (0, None);
+                for &(top, color) in &tops {
+                    let covered = top.min(high).saturating_sub(bottom.max(low));
+                    if covered > best.0 {
+                        best = (covered, Some(color));
+                    }
+                    bottom = top;
+                }
+                let fill = (height.min(high) - low) as usize;
+                let cell = &mut buf[(area.x + col as u16, area.bottom() - 1 - row as u16)];
+                cell.set_symbol(BARS[fill]);
+                if let Some(color) = best.1 {
+                    cell.set_fg(color);
+                }
+            }
+        }
+    }
+}
@@ -1,19 +1,30 @@
+mod components;
 mod widgets;
 
+use std::cell::Cell;
+
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, Gauge, Paragraph, Sparkline, Row, Table},
     Frame,
 };
 
-use crate::app::App;
-use crate::collectors::memory::format_bytes;
+use crate::app::{App, Dialog, View};
+use crate::control::Signal;
+use crate::themes::Theme;
+use crate::ui::components::gauge::StackedGauge;
+use crate::ui::components::heatmap::Heatmap;
+use crate::ui::components::sparkline::StackedSparkline;
+use crate::collectors::cpu::{format_frequency, CoreGroup, CpuData, CpuTimeKind};
+use crate::collectors::interrupts::format_rate;
+use crate::collectors::memory::{format_bytes, CompressedPool, MemInfo, MemoryData};
 use crate::collectors::system::format_uptime;
 use crate::collectors::network::{format_speed, format_total};
-use crate::collectors::disk::format_space;
-use crate::collectors::process::format_proc_memory;
+use crate::collectors::disk::{format_space, RaidArray};
+use crate::collectors::process::{format_proc_memory, format_proc_rate};
+use crate::collectors::watch::WatchEvent;
 
 /// Main render function
 pub fn render(frame: &mut Frame, app: &App) {
@@ -32,6 +43,29 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Render header
     render_header(frame, app, main_chunks[0]);
 
+    match app.view {
+        View::Dashboard => render_dashboard(frame, app, main_chunks[1]),
+        View::Processes => widgets::process_widget::render_process_view(frame, app, main_chunks[1]),
+        View::Users => widgets::process_widget::render_user_view(frame, app, main_chunks[1]),
+        View::Services => widgets::process_widget::render_service_view(frame, app, main_chunks[1]),
+        View::Interrupts => widgets::interrupt_widget::render_interrupt_view(frame, app, main_chunks[1]),
+        View::Disks => widgets::disk_widget::render_disk_view(frame, app, main_chunks[1]),
+    }
+
+    // Render footer
+    render_footer(frame, app, main_chunks[2]);
+
+    // Help overlay (on top of everything)
+    if app.show_help {
+        render_help_overlay(frame, app);
+    }
+
+    if let Some(ref dialog) = app.dialog {
+        render_dialog(frame, app, dialog);
+    }
+}
+
+fn render_dashboard(frame: &mut Frame, app: &App, area: Rect) {
     // Content: Two columns
     let content_cols = Layout::default()
         .direction(Direction::Horizontal)
@@ -39,14 +73,29 @@ pub fn render(frame: &mut Frame, app: &App) {
             Constraint::Percentage(60),  // Left column
             Constraint::Percentage(40),  // Right column
         ])
-        .split(main_chunks[1]);
+        .split(area);
+
+    // CPU panel grows for a per-core heatmap while leaving the process panel 8 rows
+    let groups = app.collectors.cpu.data.core_groups();
+    let memory_rows = 11 + memory_extra_rows(&app.collectors.memory.data);
+    let spare = content_cols[0].height.saturating_sub(10 + memory_rows + 6 + 8);
+    let core_rows = match heatmap_rows(&groups, 3 + spare) {
+        Some(rows) => rows.len() as u16,
+        None => {
+            // Text grid at the widest the panel allows (inside border, margin and group tag)
+            let width = content_cols[0].width.saturating_sub(4 + 5) as usize;
+            let per_row = (width / core_cell_width(&groups, false)).max(1);
+            (grid_lines(&groups, per_row) as u16).min(3 + spare)
+        }
+    }
+    .max(3);
 
     // Left column: CPU, Memory/Swap, GPU
     let left_col = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(10), // CPU
-            Constraint::Length(6),  // Memory
+            Constraint::Length(7 + core_rows), // CPU
+            Constraint::Length(memory_rows), // Memory
             Constraint::Length(6),  // GPU
             Constraint::Min(3),     // Processes
         ])
@@ -57,27 +106,28 @@ pub fn render(frame: &mut Frame, app: &App) {
     render_gpu(frame, app, left_col[2]);
     render_processes(frame, app, left_col[3]);
 
-    // Right column: Network, Disk, System Info
+    // Right column: Network, Disk, Watched processes (if any), System Info
+    let watched = app.collectors.process.watched.len() as u16;
     let right_col = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(7),  // Network
-            Constraint::Length(6),  // Disk
-            Constraint::Min(3),     // System
+            Constraint::Length(10), // Disk
+            Constraint::Length(7),  // Load / pressure
+            Constraint::Length(if watched > 0 { 2 + watched * 3 } else { 0 }), // Watched
+            Constraint::Min(3),     // Sensors
+            Constraint::Length(6),  // System
         ])
         .split(content_cols[1]);
 
     render_network(frame, app, right_col[0]);
     render_disk(frame, app, right_col[1]);
-    render_system_info(frame, app, right_col[2]);
-
-    // Render footer
-    render_footer(frame, app, main_chunks[2]);
-
-    // Help overlay (on top of everything)
-    if app.show_help {
-        render_help_overlay(frame, app);
+    render_pressure(frame, app, right_col[2]);
+    if watched > 0 {
+        render_watched(frame, app, right_col[3]);
     }
+    render_sensors(frame, app, right_col[4]);
+    render_system_info(frame, app, right_col[5]);
 }
 
 fn render_header(frame: &mut Frame, app: &App, area: Rect) {
@@ -121,12 +171,16 @@ fn render_cpu(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title(format!(
-            " CPU ─ {} ─ {}C/{}T ",
-            truncate_str(&data.model, 30),
-            data.physical_cores,
-            data.logical_cores
-        ));
+        .title(Line::from(vec![
+            Span::raw(format!(
+                " CPU ─ {} ─ {}{}C/{}T ",
+                truncate_str(&data.model, 30),
+                layout_summary(data),
+                data.physical_cores,
+                data.logical_cores
+            )),
+            throttle_indicator(app),
+        ]));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -137,50 +191,323 @@ fn render_cpu(frame: &mut Frame, app: &App, area: Rect) {
         .constraints([
             Constraint::Length(1), // Global usage
             Constraint::Length(1), // Frequency
-            Constraint::Length(3), // Per-core bars
+            Constraint::Length(inner.height.saturating_sub(5).max(3)), // Per-core heatmap or bars
             Constraint::Min(1),    // Sparkline
         ])
         .split(inner);
 
-    // Global usage gauge
-    let usage_color = theme.usage_color(data.global_usage);
-    let gauge = Gauge::default()
-        .gauge_style(Style::default().fg(usage_color).bg(theme.bar_empty))
-        .ratio((data.global_usage as f64 / 100.0).min(1.0))
-        .label(format!("Global: {:.1}%", data.global_usage));
-    frame.render_widget(gauge, cpu_layout[0]);
-
-    // Frequency
-    let freq_text = Paragraph::new(format!(
-        "Freq: {} MHz",
-        data.frequency_mhz
-    )).style(Style::default().fg(theme.muted));
-    frame.render_widget(freq_text, cpu_layout[1]);
+    // Global usage gauge, stacked by time category when /proc/stat is available
+    if let Some(times) = data.times {
+        let segments: Vec<(f32, Color)> = CpuTimeKind::ALL
+            .iter()
+            .map(|&kind| (times.get(kind), cpu_time_color(theme, kind)))
+            .collect();
+        let breakdown: Vec<String> = CpuTimeKind::ALL
+            .iter()
+            .map(|&kind| format!("{} {:.1}", kind.short(), times.get(kind)))
+            .collect();
+        let gauge = StackedGauge::new(&segments, theme.bar_empty)
+            .label(format!("Global: {:.1}% │ {}", data.global_usage, breakdown.join(" ")));
+        frame.render_widget(gauge, cpu_layout[0]);
+    } else {
+        let usage_color = theme.usage_color(data.global_usage);
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(usage_color).bg(theme.bar_empty))
+            .ratio((data.global_usage as f64 / 100.0).min(1.0))
+            .label(format!("Global: {:.1}%", data.global_usage));
+        frame.render_widget(gauge, cpu_layout[0]);
+    }
+
+    // Frequency: average, policy range, governor / driver / EPP
+    let mut freq_spans = vec![Span::styled(
+        format!("Freq: {} MHz", data.frequency_mhz),
+        Style::default().fg(theme.muted),
+    )];
+    if data.has_cpufreq {
+        let (min, max) = data.frequency_range();
+        let mut details = vec![format!("{}-{}", format_frequency(min), format_frequency(max))];
+        details.extend([data.governor(), data.driver()].into_iter().filter(|s| !s.is_empty()));
+        if let Some(epp) = data.energy_preference() {
+            details.push(format!("EPP {}", epp));
+        }
+        if let Some(hardware_max) = data.policy_cap() {
+            details.push(format!("freq cap (hw {})", format_frequency(hardware_max)));
+        }
+        freq_spans.push(Span::styled(format!(" │ {}", details.join(" │ ")), Style::default().fg(theme.muted)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(freq_spans)), cpu_layout[1]);
+
+    // Per-core cells: one per physical core with the usage of each SMT sibling,
+    // a "w"/"s" flag for threads losing 10%+ to iowait / steal, and the current frequency
+    let cores_area = cpu_layout[2];
+    let groups = data.core_groups();
+    let heat_rows = heatmap_rows(&groups, cores_area.height);
+    let tag_width = groups.iter().map(|g| group_tag(data, g).len()).max().unwrap_or(0);
+    let label_width = if tag_width > 0 { tag_width + 1 } else { 0 };
+    // The text grid fits every core by widening rows, dropping frequencies if need be
+    let grid_per_row = grid_per_row(&groups, cores_area.height, (cores_area.width as usize).saturating_sub(label_width));
+    let show_freq = data.per_core_frequency.iter().any(|f| f.current_mhz > 0)
+        && (heat_rows.is_some() || label_width + grid_per_row * core_cell_width(&groups, true) <= cores_area.width as usize);
+    let cell_width = core_cell_width(&groups, show_freq);
+    let core_cell = |cpus: &[usize]| {
+        let usage = |cpu: usize| data.per_core_usage.get(cpu).copied().unwrap_or(0.0);
+        let hottest = cpus.iter().map(|&c| usage(c)).fold(0.0, f32::max);
+        let bracket = Style::default().fg(theme.usage_color(hottest));
+        let mut spans = vec![Span::styled("[", bracket)];
+        for &cpu in cpus {
+            spans.push(Span::styled(format!("{:3.0}", usage(cpu)), Style::default().fg(theme.usage_color(usage(cpu)))));
+            spans.push(match data.per_core_times.get(cpu) {
+                Some(t) if t.steal >= 10.0 => Span::styled("s", Style::default().fg(theme.danger)),
+                Some(t) if t.iowait >= 10.0 => Span::styled("w", Style::default().fg(theme.warning)),
+                _ => Span::raw(" "),
+            });
+        }
+        if let Some(freq) = cpus.first().and_then(|&c| data.per_core_frequency.get(c)).filter(|_| show_freq) {
+            spans.push(Span::styled(
+                format!(" {:>5}", format_frequency(freq.current_mhz)),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        spans.push(Span::styled("]", bracket));
+        spans
+    };
+    let tag_span = |group: &CoreGroup, first: bool| {
+        let tag = if first { group_tag(data, group) } else { String::new() };
+        Span::styled(format!("{:<1$}", tag, label_width), Style::default().fg(theme.accent))
+    };
 
-    // Per-core usage (compact)
-    let cores_per_row = 8;
-    let mut core_lines = Vec::new();
-    for chunk in data.per_core_usage.chunks(cores_per_row) {
-        let spans: Vec<Span> = chunk
+    let heat_rows = heat_rows.filter(|_| cores_area.width as usize > label_width + 4 + 2 * cell_width + 10);
+    if let Some(heat_rows) = heat_rows {
+        // Heatmap: group and CPU number, history, then the current cell of that row.
+        // Rows holding two CPUs pack them into half blocks; a lone CPU gets an empty lower half.
+        let packed = heat_rows.iter().any(|(_, cpus)| cpus.len() > 1);
+        // "12", or "12/44" for a row holding two CPUs
+        let number_width = heat_rows
             .iter()
-            .map(|&usage| {
-                let color = theme.usage_color(usage);
-                Span::styled(format!("[{:2.0}]", usage), Style::default().fg(color))
+            .map(|(_, cpus)| cpus.iter().map(|c| c.to_string().len() + 1).sum::<usize>() - 1)
+            .max()
+            .unwrap_or(1)
+            .max(3);
+        let [label_area, map_area, cell_area] = Layout::horizontal([
+            Constraint::Length((label_width + number_width + 2) as u16),
+            Constraint::Min(10),
+            Constraint::Length(cell_width as u16 + 1),
+        ])
+        .areas(cores_area);
+        let mut series: Vec<Vec<u64>> = Vec::new();
+        for (_, cpus) in &heat_rows {
+            for slot in 0..if packed { 2 } else { 1 } {
+                series.push(cpus.get(slot).and_then(|&c| cpu.per_core_history.get(c)).map(|h| h.as_vec()).unwrap_or_default());
+            }
+        }
+        frame.render_widget(Heatmap::new(&series, theme), map_area);
+
+        let mut labels = Vec::new();
+        let mut cells = Vec::new();
+        for (row, (group, cpus)) in heat_rows.iter().enumerate() {
+            let first = row == 0 || heat_rows[row - 1].0 != *group;
+            // ┐ / ┘ join SMT siblings drawn on consecutive rows
+            let core = groups[*group].cores.iter().find(|c| c.contains(&cpus[0]));
+            let pair = match core {
+                Some(core) if cpus.len() == 1 && core.len() > 1 => {
+                    if core[0] == cpus[0] { "┐" } else if core.last() == Some(&cpus[0]) { "┘" } else { "│" }
+                }
+                _ => " ",
+            };
+            let numbers: Vec<String> = cpus.iter().map(|c| c.to_string()).collect();
+            labels.push(Line::from(vec![
+                tag_span(&groups[*group], first),
+                Span::styled(format!("{:>1$}{2}", numbers.join("/"), number_width, pair), Style::default().fg(theme.muted)),
+            ]));
+            let mut spans = vec![Span::raw(" ")];
+            spans.extend(core_cell(cpus));
+            cells.push(Line::from(spans));
+        }
+        frame.render_widget(Paragraph::new(labels), label_area);
+        frame.render_widget(Paragraph::new(cells), cell_area);
+    } else {
+        let mut core_lines = Vec::new();
+        for group in &groups {
+            for (row, chunk) in group.cores.chunks(grid_per_row).enumerate() {
+                let mut spans = Vec::new();
+                if label_width > 0 {
+                    spans.push(tag_span(group, row == 0));
+                }
+                for core in chunk {
+                    spans.extend(core_cell(core));
+                }
+                core_lines.push(Line::from(spans));
+            }
+        }
+        frame.render_widget(Paragraph::new(core_lines), cores_area);
+    }
+
+    // Sparklines: usage, and average frequency when known
+    let [usage_area, freq_area] = Layout::horizontal([
+        Constraint::Percentage(if show_freq { 70 } else { 100 }),
+        Constraint::Min(0),
+    ])
+    .areas(cpu_layout[3]);
+    if data.times.is_some() {
+        let series: Vec<(Vec<u64>, Color)> = cpu.time_history
+            .iter()
+            .zip(CpuTimeKind::ALL)
+            .map(|(history, kind)| (history.as_vec(), cpu_time_color(theme, kind)))
+            .collect();
+        frame.render_widget(StackedSparkline::new(&series, 100), usage_area);
+    } else {
+        let history = cpu.history.as_vec();
+        if !history.is_empty() {
+            let sparkline = Sparkline::default()
+                .data(&history)
+                .max(100)
+                .style(Style::default().fg(theme.graph_line));
+            frame.render_widget(sparkline, usage_area);
+        }
+    }
+    let freq_history = cpu.frequency_history.as_vec();
+    if show_freq && !freq_history.is_empty() {
+        let (_, max) = data.frequency_range();
+        let max_val = freq_history.iter().max().copied().unwrap_or(1).max(max).max(1);
+        let sparkline = Sparkline::default()
+            .data(&freq_history)
+            .max(max_val)
+            .style(Style::default().fg(theme.accent));
+        frame.render_widget(sparkline, freq_area.inner(Margin::new(1, 0)));
+    }
+}
+
+/// Heatmap rows for the per-core display, each with its group index and CPUs: one
+/// row per logical CPU, else one per physical core (SMT pairs share a row), else
+/// consecutive CPUs paired within each group. None when even that exceeds `room`.
+fn heatmap_rows(groups: &[CoreGroup], room: u16) -> Option<Vec<(usize, Vec<usize>)>> {
+    let room = room as usize;
+    let per_cpu: Vec<(usize, Vec<usize>)> = groups
+        .iter()
+        .enumerate()
+        .flat_map(|(g, group)| group.cores.iter().flatten().map(move |&cpu| (g, vec![cpu])))
+        .collect();
+    if per_cpu.len() <= room {
+        return Some(per_cpu);
+    }
+    let cores = groups.iter().map(|g| g.cores.len()).sum::<usize>();
+    if cores <= room && groups.iter().flat_map(|g| &g.cores).all(|c| c.len() <= 2) {
+        return Some(
+            groups
+                .iter()
+                .enumerate()
+                .flat_map(|(g, group)| group.cores.iter().map(move |core| (g, core.clone())))
+                .collect(),
+        );
+    }
+    let pairs: Vec<(usize, Vec<usize>)> = groups
+        .iter()
+        .enumerate()
+        .flat_map(|(g, group)| {
+            let cpus: Vec<usize> = group.cores.iter().flatten().copied().collect();
+            cpus.chunks(2).map(|pair| (g, pair.to_vec())).collect::<Vec<_>>()
+        })
+        .collect();
+    (pairs.len() <= room).then_some(pairs)
+}
+
+/// Physical cores per text grid line: at least 8 (or a whole group), widened
+/// until every group fits `height` lines, but never beyond what fits `width`
+/// without frequencies
+fn grid_per_row(groups: &[CoreGroup], height: u16, width: usize) -> usize {
+    let widest_group = groups.iter().map(|g| g.cores.len()).max().unwrap_or(1).max(1);
+    let fits = (width / core_cell_width(groups, false)).max(1);
+    let mut per_row = widest_group.min(8);
+    while per_row < widest_group.min(fits) && grid_lines(groups, per_row) > height as usize {
+        per_row += 1;
+    }
+    per_row
+}
+
+fn grid_lines(groups: &[CoreGroup], per_row: usize) -> usize {
+    groups.iter().map(|g| g.cores.len().div_ceil(per_row.max(1))).sum()
+}
+
+/// "[ 12  34  3.2G]": brackets, 4 columns per SMT thread, optional frequency
+fn core_cell_width(groups: &[CoreGroup], frequency: bool) -> usize {
+    let threads = groups.iter().flat_map(|g| &g.cores).map(Vec::len).max().unwrap_or(1);
+    2 + 4 * threads + if frequency { 6 } else { 0 }
+}
+
+/// Loud while the CPU is throttled, quieter once it has been at some point
+fn throttle_indicator(app: &App) -> Span<'static> {
+    let theme = &app.theme;
+    let cpu = &app.collectors.cpu;
+    if !cpu.data.throttling.is_empty() {
+        let reasons: Vec<String> = cpu.data.throttling
+            .iter()
+            .map(|&reason| {
+                let cpus = cpu.throttle_events.iter().rev().find(|e| e.reason == reason).map(|e| e.cpus.as_slice());
+                match cpus {
+                    Some([one]) => format!("{} cpu{}", reason.label(), one),
+                    Some(cpus) if cpus.len() < cpu.data.per_core_usage.len() => {
+                        format!("{} {} CPUs", reason.label(), cpus.len())
+                    }
+                    _ => reason.label().to_string(),
+                }
             })
             .collect();
-        core_lines.push(Line::from(spans));
+        return Span::styled(
+            format!("▼ THROTTLED: {} ", reasons.join(", ")),
+            Style::default().fg(theme.danger).bold().reversed(),
+        );
     }
-    let cores_para = Paragraph::new(core_lines);
-    frame.render_widget(cores_para, cpu_layout[2]);
+    match cpu.throttle_events.last() {
+        Some(last) => Span::styled(
+            format!(
+                "▼ throttled {}-{} ({}, ×{}) ",
+                last.started.format("%H:%M:%S"),
+                last.last_seen.format("%H:%M:%S"),
+                last.reason.label(),
+                cpu.throttle_events.len(),
+            ),
+            Style::default().fg(theme.warning),
+        ),
+        None => Span::raw(""),
+    }
+}
 
-    // Sparkline
-    let history = cpu.history.as_vec();
-    if !history.is_empty() {
-        let sparkline = Sparkline::default()
-            .data(&history)
-            .max(100)
-            .style(Style::default().fg(theme.graph_line));
-        frame.render_widget(sparkline, cpu_layout[3]);
+/// "2S 4N " prefix for the CPU title on multi-socket / NUMA machines
+fn layout_summary(data: &CpuData) -> String {
+    let mut summary = String::new();
+    if data.sockets() > 1 {
+        summary.push_str(&format!("{}S ", data.sockets()));
+    }
+    if data.numa_nodes() > 1 {
+        summary.push_str(&format!("{}N ", data.numa_nodes()));
+    }
+    summary
+}
+
+/// "S1", "N3" or "S1N3" when the machine has several sockets / NUMA nodes
+fn group_tag(data: &CpuData, group: &CoreGroup) -> String {
+    let mut tag = String::new();
+    if data.sockets() > 1 {
+        tag.push_str(&format!("S{}", group.socket));
+    }
+    if data.numa_nodes() > 1 {
+        if let Some(node) = group.node {
+            tag.push_str(&format!("N{}", node));
+        }
+    }
+    tag
+}
+
+/// Colour of a CPU time category in the stacked gauge and graph
+fn cpu_time_color(theme: &Theme, kind: CpuTimeKind) -> Color {
+    match kind {
+        CpuTimeKind::User => theme.primary,
+        CpuTimeKind::Nice => theme.secondary,
+        CpuTimeKind::System => theme.accent,
+        CpuTimeKind::Irq => theme.graph_line,
+        CpuTimeKind::SoftIrq => theme.muted,
+        CpuTimeKind::IoWait => theme.warning,
+        CpuTimeKind::Steal => theme.danger,
     }
 }
 
@@ -192,7 +519,7 @@ fn render_memory(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title(" MEMORY ");
+        .title(Line::from(vec![Span::raw(" MEMORY "), oom_indicator(app)]));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -202,23 +529,101 @@ fn render_memory(frame: &mut Frame, app: &App, area: Rect) {
         .margin(1)
         .constraints([
             Constraint::Length(1), // RAM gauge
+            Constraint::Length(if data.meminfo.is_some() { 2 } else { 0 }), // Breakdown
+            Constraint::Length(if data.paging.is_some() { 1 } else { 0 }), // Paging
             Constraint::Length(1), // Details
-            Constraint::Min(1),    // Sparkline
+            Constraint::Length(memory_extra_rows(data)), // NUMA nodes, zram, zswap
+            Constraint::Min(1),    // Sparklines
         ])
         .split(inner);
 
-    // RAM gauge
-    let ram_color = theme.usage_color(data.usage_percent);
-    let ram_gauge = Gauge::default()
-        .gauge_style(Style::default().fg(ram_color).bg(theme.bar_empty))
-        .ratio((data.usage_percent as f64 / 100.0).min(1.0))
-        .label(format!(
-            "RAM: {:.1}% ({}/{})",
-            data.usage_percent,
-            format_bytes(data.used),
-            format_bytes(data.total)
-        ));
-    frame.render_widget(ram_gauge, mem_layout[0]);
+    let ram_label = format!(
+        "RAM: {:.1}% ({}/{})",
+        data.usage_percent,
+        format_bytes(data.used),
+        format_bytes(data.total)
+    );
+    if let Some(info) = data.meminfo.filter(|i| i.total > 0) {
+        // Stacked bar of MemTotal: what can't be reclaimed first, then caches, then free
+        let segments = memory_segments(theme, &info);
+        let percents: Vec<(f32, Color)> = segments
+            .iter()
+            .map(|&(_, bytes, color)| (bytes as f32 / info.total as f32 * 100.0, color))
+            .collect();
+        let reclaimable = info.page_cache() + info.buffers + info.slab_reclaimable;
+        let gauge = StackedGauge::new(&percents, theme.bar_empty)
+            .label(format!("{} + {} cache", ram_label, format_bytes(reclaimable)));
+        frame.render_widget(gauge, mem_layout[0]);
+
+        let mut legend = Vec::new();
+        for (name, bytes, color) in segments.into_iter().filter(|&(_, bytes, _)| bytes > 0) {
+            legend.push(Span::styled("■ ", Style::default().fg(color)));
+            legend.push(Span::styled(
+                format!("{} {}  ", name, format_proc_memory(bytes)),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        let commit = info.commit_percent();
+        let commit_color = if commit > 100.0 { theme.danger } else { theme.usage_color(commit) };
+        let mut activity = vec![
+            Span::styled(
+                format!(
+                    "Dirty {} │ Writeback {} │ Mapped {} │ ",
+                    format_proc_memory(info.dirty),
+                    format_proc_memory(info.writeback),
+                    format_proc_memory(info.mapped)
+                ),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                format!(
+                    "Commit {}/{} ({:.0}%)",
+                    format_proc_memory(info.committed),
+                    format_proc_memory(info.commit_limit),
+                    commit
+                ),
+                Style::default().fg(commit_color),
+            ),
+        ];
+        if info.hugepages > 0 {
+            activity.push(Span::styled(
+                format!(
+                    " │ Huge {}/{} free",
+                    format_proc_memory(info.hugepages_free),
+                    format_proc_memory(info.hugepages)
+                ),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        frame.render_widget(Paragraph::new(vec![Line::from(legend), Line::from(activity)]), mem_layout[1]);
+    } else {
+        let ram_color = theme.usage_color(data.usage_percent);
+        let ram_gauge = Gauge::default()
+            .gauge_style(Style::default().fg(ram_color).bg(theme.bar_empty))
+            .ratio((data.usage_percent as f64 / 100.0).min(1.0))
+            .label(ram_label);
+        frame.render_widget(ram_gauge, mem_layout[0]);
+    }
+
+    // Paging: swapping and major faults mean the working set no longer fits
+    if let Some(paging) = data.paging {
+        let busy = |rate: f64| if rate > 0.0 { theme.warning } else { theme.muted };
+        let line = Line::from(vec![
+            Span::styled("Swap in/out ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("{}/{} pg/s", format_rate(paging.swap_in), format_rate(paging.swap_out)),
+                Style::default().fg(busy(paging.swap_in + paging.swap_out)),
+            ),
+            Span::styled(format!(" │ Faults {}/s, major ", format_rate(paging.faults)), Style::default().fg(theme.muted)),
+            Span::styled(format!("{}/s", format_rate(paging.major_faults)), Style::default().fg(busy(paging.major_faults))),
+            Span::styled(" │ Scan/steal ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("{}/{} pg/s", format_rate(paging.scanned), format_rate(paging.stolen)),
+                Style::default().fg(busy(paging.scanned)),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(line), mem_layout[2]);
+    }
 
     // Details
     let details = Paragraph::new(format!(
@@ -228,17 +633,134 @@ fn render_memory(frame: &mut Frame, app: &App, area: Rect) {
         format_bytes(data.swap_used),
         format_bytes(data.swap_total)
     )).style(Style::default().fg(theme.muted));
-    frame.render_widget(details, mem_layout[1]);
+    frame.render_widget(details, mem_layout[3]);
+
+    // One row per NUMA node (used, with page cache set apart), zram device and zswap
+    let extra_rows = mem_layout[4].rows().collect::<Vec<_>>();
+    for (node, row) in data.nodes.iter().zip(&extra_rows) {
+        let pct = |bytes: u64| bytes as f32 / node.total.max(1) as f32 * 100.0;
+        let file = node.file.min(node.used());
+        let segments = [
+            (pct(node.used() - file), theme.usage_color(pct(node.used() - file))),
+            (pct(file), theme.secondary),
+        ];
+        let gauge = StackedGauge::new(&segments, theme.bar_empty).label(format!(
+            "Node {}: {:.1}% ({}/{}) incl. {} cache",
+            node.node,
+            pct(node.used()),
+            format_bytes(node.used()),
+            format_bytes(node.total),
+            format_bytes(file)
+        ));
+        frame.render_widget(gauge, *row);
+    }
+    let pool_spans = |name: String, pool: &CompressedPool| {
+        if pool.original == 0 {
+            return vec![
+                Span::styled(name, Style::default().fg(theme.accent)),
+                Span::styled(" empty", Style::default().fg(theme.muted)),
+            ];
+        }
+        vec![
+            Span::styled(name, Style::default().fg(theme.accent)),
+            Span::styled(
+                format!(" {} → {} ", format_proc_memory(pool.original), format_proc_memory(pool.compressed)),
+                Style::default().fg(theme.foreground),
+            ),
+            Span::styled(format!("({:.1}x)", pool.ratio()), Style::default().fg(theme.primary)),
+        ]
+    };
+    let mut compressed = Vec::new();
+    for zram in &data.zram {
+        let mut spans = pool_spans(format!("{} {}", zram.name, zram.algorithm).trim().to_string(), &zram.pool);
+        spans.push(Span::styled(
+            format!(
+                " │ {} RAM │ {} disk",
+                format_proc_memory(zram.memory_used),
+                format_proc_memory(zram.disk_size)
+            ),
+            Style::default().fg(theme.muted),
+        ));
+        compressed.push(Line::from(spans));
+    }
+    if let Some(zswap) = &data.zswap {
+        compressed.push(Line::from(pool_spans("zswap".to_string(), zswap)));
+    }
+    for (line, row) in compressed.into_iter().zip(extra_rows.iter().skip(data.nodes.len())) {
+        frame.render_widget(Paragraph::new(line), *row);
+    }
 
-    // Sparkline
+    // Sparklines: usage; swap in / swap out / major faults, all faults and
+    // reclaim (pages stolen, topped up to pages scanned) per second
+    let [usage_area, paging_area] = Layout::horizontal([
+        Constraint::Percentage(if data.paging.is_some() { 50 } else { 100 }),
+        Constraint::Min(0),
+    ])
+    .areas(mem_layout[5]);
     let history = mem.history.as_vec();
     if !history.is_empty() {
         let sparkline = Sparkline::default()
             .data(&history)
             .max(100)
             .style(Style::default().fg(theme.graph_line));
-        frame.render_widget(sparkline, mem_layout[2]);
+        frame.render_widget(sparkline, usage_area);
     }
+    if data.paging.is_some() {
+        let [swap_area, fault_area, reclaim_area] = Layout::horizontal([Constraint::Ratio(1, 3); 3]).areas(paging_area);
+        let mut stacked = |series: &[(Vec<u64>, Color)], area: Rect| {
+            let len = series[0].0.len();
+            let peak = (0..len).map(|i| series.iter().map(|(s, _)| s.get(i).copied().unwrap_or(0)).sum::<u64>()).max();
+            frame.render_widget(StackedSparkline::new(series, peak.unwrap_or(0).max(1)), area.inner(Margin::new(1, 0)));
+        };
+        stacked(
+            &[
+                (mem.major_fault_history.as_vec(), theme.accent),
+                (mem.swap_in_history.as_vec(), theme.warning),
+                (mem.swap_out_history.as_vec(), theme.danger),
+            ],
+            swap_area,
+        );
+        stacked(&[(mem.fault_history.as_vec(), theme.secondary)], fault_area);
+        let stolen = mem.steal_history.as_vec();
+        let unreclaimed = mem.scan_history.as_vec().iter().zip(&stolen).map(|(scan, steal)| scan.saturating_sub(*steal)).collect();
+        stacked(&[(stolen, theme.primary), (unreclaimed, theme.warning)], reclaim_area);
+    }
+}
+
+/// Memory panel rows beyond the fixed ones: NUMA nodes, zram devices, zswap
+fn memory_extra_rows(data: &MemoryData) -> u16 {
+    (data.nodes.len() + data.zram.len() + data.zswap.iter().count()) as u16
+}
+
+/// Every OOM kill since startup stays in the memory title
+fn oom_indicator(app: &App) -> Span<'static> {
+    let data = &app.collectors.memory.data;
+    match data.last_oom_kill {
+        Some(at) => Span::styled(
+            format!(
+                "✗ {} OOM kill{}, last {} ",
+                data.oom_kills,
+                if data.oom_kills == 1 { "" } else { "s" },
+                at.format("%H:%M:%S")
+            ),
+            Style::default().fg(app.theme.danger).bold().reversed(),
+        ),
+        None => Span::raw(""),
+    }
+}
+
+/// Parts of MemTotal in use, in stacking order, with their legend name and colour
+fn memory_segments(theme: &Theme, info: &MemInfo) -> Vec<(&'static str, u64, Color)> {
+    vec![
+        ("anon", info.anon, theme.primary),
+        ("shmem", info.shmem, theme.accent),
+        ("slab", info.slab_unreclaimable, theme.warning),
+        ("kernel", info.kernel_other(), theme.muted),
+        ("huge", info.hugepages, theme.danger),
+        ("buffers", info.buffers, theme.graph_line),
+        ("cache", info.page_cache(), theme.secondary),
+        ("slab recl", info.slab_reclaimable, theme.success),
+    ]
 }
 
 fn render_gpu(frame: &mut Frame, app: &App, area: Rect) {
@@ -310,6 +832,246 @@ fn render_gpu(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(details, gpu_layout[2]);
 }
 
+fn render_pressure(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let pressure = &app.collectors.pressure;
+    let data = &pressure.data;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(" LOAD / PRESSURE ");
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [load_area, cpu_area, memory_area, io_area, graph_area] = Layout::vertical([
+        Constraint::Length(1), // Load average
+        Constraint::Length(1), // CPU PSI
+        Constraint::Length(1), // Memory PSI
+        Constraint::Length(1), // I/O PSI
+        Constraint::Min(0),    // History
+    ])
+    .areas(inner);
+
+    // Load relative to the core count decides the colour
+    let cores = app.collectors.cpu.data.logical_cores.max(1) as f64;
+    let mut load_spans = vec![
+        Span::styled("Load ", Style::default().fg(theme.muted)),
+        Span::styled(
+            format!("{:.2} {:.2} {:.2}", data.load_1, data.load_5, data.load_15),
+            Style::default().fg(theme.usage_color((data.load_1 / cores * 100.0) as f32)),
+        ),
+    ];
+    if data.tasks > 0 {
+        load_spans.push(Span::styled(
+            format!(" │ {}/{} runnable", data.runnable, data.tasks),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(load_spans)), load_area);
+
+    if !data.psi_available() {
+        let note = Paragraph::new(vec![
+            Line::from(Span::styled("PSI unavailable", Style::default().fg(theme.warning))),
+            Line::from(Span::styled(
+                "needs Linux 4.20+ with CONFIG_PSI (and not psi=0)",
+                Style::default().fg(theme.muted),
+            )),
+        ]);
+        frame.render_widget(note, cpu_area.union(io_area));
+    } else {
+        for (name, psi, area) in [
+            ("CPU", data.cpu, cpu_area),
+            ("MEM", data.memory, memory_area),
+            ("I/O", data.io, io_area),
+        ] {
+            let Some(psi) = psi else {
+                frame.render_widget(
+                    Paragraph::new(format!("{} n/a", name)).style(Style::default().fg(theme.muted)),
+                    area,
+                );
+                continue;
+            };
+            let full = psi.full.map(|f| format!(" │ full {:.1}", f.avg10)).unwrap_or_default();
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(pressure_color(theme, psi.some.avg10)).bg(theme.bar_empty))
+                .ratio((psi.some.avg10 as f64 / 100.0).clamp(0.0, 1.0))
+                .label(format!(
+                    "{} some {:.1}/{:.1}/{:.1}{} │ {:.0}s stalled",
+                    name,
+                    psi.some.avg10,
+                    psi.some.avg60,
+                    psi.some.avg300,
+                    full,
+                    psi.some.total_us as f64 / 1_000_000.0
+                ));
+            frame.render_widget(gauge, area);
+        }
+    }
+
+    // Load │ CPU │ MEM │ I/O pressure history, scaled to at least a quarter
+    // of the full range (all cores busy / 100% stalled) so noise stays flat
+    let graphs = Layout::horizontal([Constraint::Ratio(1, 4); 4]).split(graph_area);
+    let histories = [
+        (pressure.load_history.as_vec(), (cores * 100.0) as u64, theme.graph_line),
+        (pressure.cpu_history.as_vec(), 100, theme.primary),
+        (pressure.memory_history.as_vec(), 100, theme.accent),
+        (pressure.io_history.as_vec(), 100, theme.warning),
+    ];
+    for ((history, range, color), &graph) in histories.iter().zip(graphs.iter()) {
+        let max_val = history.iter().max().copied().unwrap_or(1).max(*range / 4).max(1);
+        let sparkline = Sparkline::default()
+            .data(history)
+            .max(max_val)
+            .style(Style::default().fg(*color));
+        frame.render_widget(sparkline, graph.inner(Margin::new(1, 0)));
+    }
+}
+
+/// PSI is alarming much earlier than utilisation: 10% of time stalled already hurts
+fn pressure_color(theme: &Theme, percent: f32) -> Color {
+    match percent {
+        p if p < 10.0 => theme.success,
+        p if p < 40.0 => theme.warning,
+        _ => theme.danger,
+    }
+}
+
+fn render_sensors(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let sensors = &app.collectors.sensors;
+    let chips = &sensors.data.chips;
+
+    let count: usize = chips.iter().map(|c| c.sensors.len()).sum();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(if count > 0 { format!(" SENSORS ─ {} ", count) } else { " SENSORS ─ Not Available ".to_string() });
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if count == 0 {
+        let msg = Paragraph::new("No hwmon or thermal zone sensors found")
+            .style(Style::default().fg(theme.muted));
+        frame.render_widget(msg, inner);
+        return;
+    }
+
+    // One line per chip header and per sensor: label, temperature, limit, history
+    let mut rows = inner.rows();
+    for chip in chips {
+        let Some(header) = rows.next() else { break };
+        frame.render_widget(
+            Paragraph::new(Span::styled(chip.name.clone(), Style::default().fg(theme.accent).bold())),
+            header,
+        );
+        for sensor in &chip.sensors {
+            let Some(row) = rows.next() else { break };
+            let color = theme.usage_color(sensor.level());
+            let limit = match (sensor.crit, sensor.max) {
+                (Some(crit), _) => format!("crit {:.0}°", crit),
+                (None, Some(max)) => format!("max {:.0}°", max),
+                _ => String::new(),
+            };
+            let [text_area, graph_area] =
+                Layout::horizontal([Constraint::Length(35), Constraint::Min(0)]).areas(row);
+            let text = Line::from(vec![
+                Span::styled(format!(" {:<14}", truncate_str(&sensor.label, 14)), Style::default().fg(theme.muted)),
+                Span::styled(format!("{:>6.1}°C ", sensor.celsius), Style::default().fg(color)),
+                Span::styled(format!("{:<9}", limit), Style::default().fg(theme.muted)),
+            ]);
+            frame.render_widget(Paragraph::new(text), text_area);
+
+            if let Some(history) = sensors.history_for(&chip.name, &sensor.label) {
+                let data = history.as_vec();
+                let max_val = sensor.crit.or(sensor.max).map(|l| l as u64).unwrap_or(100)
+                    .max(data.iter().max().copied().unwrap_or(0))
+                    .max(1);
+                let sparkline = Sparkline::default()
+                    .data(&data)
+                    .max(max_val)
+                    .style(Style::default().fg(color));
+                frame.render_widget(sparkline, graph_area);
+            }
+        }
+    }
+}
+
+fn render_watched(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let watched = &app.collectors.process.watched;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(format!(" WATCHED ─ {} ", watched.len()));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let slots = Layout::vertical(vec![Constraint::Length(3); watched.len()]).split(inner);
+    for (w, &slot) in watched.iter().zip(slots.iter()) {
+        if slot.height == 0 {
+            break;
+        }
+        let [info_area, graph_area] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(slot);
+
+        let name = if w.name.is_empty() { w.target.spec() } else { w.name.clone() };
+        let mut spans = vec![
+            Span::styled(format!("◉ {} ", name), Style::default().fg(theme.primary).bold()),
+        ];
+        if w.is_running() {
+            let pids = match w.pids.as_slice() {
+                [pid] => format!("PID {}", pid),
+                pids => format!("{} PIDs", pids.len()),
+            };
+            spans.push(Span::styled(
+                format!("{} │ {:.1}% │ {} │ {}/s ", pids, w.cpu_percent, format_proc_memory(w.memory_bytes), format_proc_rate(w.io_rate)),
+                Style::default().fg(theme.muted),
+            ));
+        } else {
+            spans.push(Span::styled("not running ", Style::default().fg(theme.danger)));
+        }
+        // Exit / restart marker stays visible on the right even for long names
+        let event = match w.event {
+            Some(WatchEvent::Exited { pid, at }) if !w.is_running() => Some((
+                format!(" ✗ {} exited {}", pid, at.format("%H:%M:%S")),
+                theme.danger,
+            )),
+            Some(WatchEvent::Restarted { old_pid, new_pid, at }) => Some((
+                format!(" ↻ {}→{} {}", old_pid, new_pid, at.format("%H:%M:%S")),
+                theme.warning,
+            )),
+            _ => None,
+        };
+        let event_width = event.as_ref().map(|(text, _)| text.chars().count() as u16).unwrap_or(0);
+        let [stats_area, event_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(event_width)]).areas(info_area);
+        frame.render_widget(Paragraph::new(Line::from(spans)), stats_area);
+        if let Some((text, color)) = event {
+            frame.render_widget(Paragraph::new(text).style(Style::default().fg(color)), event_area);
+        }
+
+        // CPU │ RSS │ I/O sparklines side by side
+        let graphs = Layout::horizontal([Constraint::Ratio(1, 3); 3]).split(graph_area);
+        let histories = [
+            (w.cpu_history.as_vec(), theme.graph_line),
+            (w.rss_history.as_vec(), theme.accent),
+            (w.io_history.as_vec(), theme.primary),
+        ];
+        for ((history, color), &graph) in histories.iter().zip(graphs.iter()) {
+            let max_val = history.iter().max().copied().unwrap_or(1).max(1);
+            let sparkline = Sparkline::default()
+                .data(history)
+                .max(max_val)
+                .style(Style::default().fg(*color));
+            frame.render_widget(sparkline, graph.inner(Margin::new(1, 0)));
+        }
+    }
+}
+
 fn render_network(frame: &mut Frame, app: &App, area: Rect) {
     let theme = &app.theme;
     let net = &app.collectors.network;
@@ -379,7 +1141,11 @@ fn render_disk(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title(format!(" DISK ─ {} ", data.mount_point));
+        .title(Line::from(vec![
+            Span::raw(format!(" DISK ─ {} ", data.mount_point)),
+            raid_indicator(app),
+            read_only_indicator(app),
+        ]));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -390,7 +1156,9 @@ fn render_disk(frame: &mut Frame, app: &App, area: Rect) {
         .constraints([
             Constraint::Length(1), // Usage gauge
             Constraint::Length(1), // Details
-            Constraint::Min(1),    // I/O
+            Constraint::Length(1), // I/O
+            Constraint::Length(1), // Latency / utilization
+            Constraint::Min(1),    // Read / write sparklines
         ])
         .split(inner);
 
@@ -408,20 +1176,106 @@ fn render_disk(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(usage_gauge, disk_layout[0]);
 
     // Details
-    let details = Paragraph::new(format!(
-        "Free: {} │ {}",
-        format_space(data.free_space),
-        data.name
-    )).style(Style::default().fg(theme.muted));
+    let stack = disk.describe_stack(disk.summary_device()).map(|s| format!(" ({})", s)).unwrap_or_default();
+    let mut details = vec![Span::raw(format!("Free: {} │ ", format_space(data.free_space)))];
+    if let Some(inodes) = disk.summary_filesystem().and_then(|f| f.inode_percent()) {
+        details.push(Span::raw("Inodes "));
+        details.push(Span::styled(format!("{:.0}%", inodes), Style::default().fg(theme.usage_color(inodes))));
+        details.push(Span::raw(" │ "));
+    }
+    details.push(Span::raw(format!("{}{}", data.name, stack)));
+    let details = Paragraph::new(Line::from(details)).style(Style::default().fg(theme.muted));
     frame.render_widget(details, disk_layout[1]);
 
     // I/O speeds
+    let Some(io) = disk.summary_io() else {
+        let io_text = Paragraph::new("I/O: no block device").style(Style::default().fg(theme.muted));
+        frame.render_widget(io_text, disk_layout[2]);
+        return;
+    };
     let io_text = Paragraph::new(format!(
-        "I/O: R {} │ W {}",
+        "I/O: R {} {} IOPS │ W {} {} IOPS",
         format_speed(data.read_speed),
-        format_speed(data.write_speed)
+        format_rate(io.read_iops),
+        format_speed(data.write_speed),
+        format_rate(io.write_iops)
     )).style(Style::default().fg(theme.primary));
     frame.render_widget(io_text, disk_layout[2]);
+
+    let latency = Line::from(vec![
+        Span::styled(format!("await {:.1} ms │ queue {:.1} │ util ", io.await_ms(), io.queue_depth), Style::default().fg(theme.muted)),
+        Span::styled(format!("{:.0}%", io.util_percent), Style::default().fg(theme.usage_color(io.util_percent))),
+    ]);
+    frame.render_widget(Paragraph::new(latency), disk_layout[3]);
+
+    // Sparklines (read / write KB/s, on one scale)
+    let [read_area, write_area] = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .areas(disk_layout[4]);
+    let reads = disk.read_history.as_vec();
+    let writes = disk.write_history.as_vec();
+    let max_val = reads.iter().chain(&writes).max().copied().unwrap_or(1).max(1);
+    let read_sparkline = Sparkline::default()
+        .data(&reads)
+        .max(max_val)
+        .style(Style::default().fg(theme.graph_line));
+    frame.render_widget(read_sparkline, read_area);
+    let write_sparkline = Sparkline::default()
+        .data(&writes)
+        .max(max_val)
+        .style(Style::default().fg(theme.accent));
+    frame.render_widget(write_sparkline, write_area.inner(Margin::new(1, 0)));
+}
+
+/// Degraded md arrays are shouted in the disk titles; a rebuild or failed spare is a warning
+fn raid_indicator(app: &App) -> Span<'static> {
+    let theme = &app.theme;
+    let raid = &app.collectors.disk.raid;
+    let progress = |array: &RaidArray| {
+        array.sync
+            .as_ref()
+            .map(|sync| format!(" {} {:.1}%", sync.action, sync.percent))
+            .unwrap_or_default()
+    };
+
+    let degraded: Vec<String> = raid
+        .iter()
+        .filter(|a| a.degraded())
+        .map(|a| format!("{} [{}]{}", a.name, a.slots, progress(a)))
+        .collect();
+    if !degraded.is_empty() {
+        return Span::styled(
+            format!("✗ RAID DEGRADED: {} ", degraded.join(", ")),
+            Style::default().fg(theme.danger).bold().reversed(),
+        );
+    }
+
+    let failed: Vec<String> = raid
+        .iter()
+        .flat_map(|a| a.members.iter().filter(|m| m.failed).map(move |m| format!("{} {}", a.name, m.name)))
+        .collect();
+    if !failed.is_empty() {
+        return Span::styled(format!("✗ failed: {} ", failed.join(", ")), Style::default().fg(theme.warning));
+    }
+    match raid.iter().find(|a| a.sync.is_some()) {
+        Some(array) => Span::styled(format!("⟳ {}{} ", array.name, progress(array)), Style::default().fg(theme.warning)),
+        None => Span::raw(""),
+    }
+}
+
+/// Filesystems that went read-only under a read-write mount, usually after I/O or metadata errors
+fn read_only_indicator(app: &App) -> Span<'static> {
+    let mounts: Vec<&str> = app.collectors.disk.filesystems
+        .iter()
+        .filter(|f| f.remounted_ro)
+        .map(|f| f.mount_point.as_str())
+        .collect();
+    if mounts.is_empty() {
+        return Span::raw("");
+    }
+    Span::styled(
+        format!("⚠ READ-ONLY after errors: {} ", mounts.join(", ")),
+        Style::default().fg(app.theme.warning).bold().reversed(),
+    )
 }
 
 fn render_processes(frame: &mut Frame, app: &App, area: Rect) {
@@ -443,12 +1297,13 @@ fn render_processes(frame: &mut Frame, app: &App, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    // Split for CPU and Memory tables
+    // Split for CPU, Memory and I/O tables
     let tables_layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(50),
-            Constraint::Percentage(50),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
         ])
         .split(inner);
 
@@ -457,7 +1312,7 @@ fn render_processes(frame: &mut Frame, app: &App, area: Rect) {
         .iter()
         .take(5)
         .map(|p| {
-            let color = theme.usage_color(p.cpu_percent);
+            let color = theme.usage_color(app.collectors.process.cpu_share(p.cpu_percent));
             Row::new(vec![
                 Span::styled(truncate_str(&p.name, 12), Style::default().fg(theme.primary)),
                 Span::styled(format!("{:5.1}%", p.cpu_percent), Style::default().fg(color)),
@@ -486,11 +1341,29 @@ fn render_processes(frame: &mut Frame, app: &App, area: Rect) {
         .header(Row::new(vec!["TOP MEM", "SIZE"]).style(Style::default().fg(theme.accent)))
         .block(Block::default());
     frame.render_widget(mem_table, tables_layout[1]);
+
+    // Top I/O table
+    let io_rows: Vec<Row> = data.top_io
+        .iter()
+        .take(5)
+        .map(|p| {
+            Row::new(vec![
+                Span::styled(truncate_str(&p.name, 12), Style::default().fg(theme.primary)),
+                Span::styled(format_proc_rate(p.io_rate()), Style::default().fg(theme.muted)),
+            ])
+        })
+        .collect();
+
+    let io_table = Table::new(io_rows, [Constraint::Min(12), Constraint::Length(6)])
+        .header(Row::new(vec!["TOP I/O", "RATE"]).style(Style::default().fg(theme.accent)))
+        .block(Block::default());
+    frame.render_widget(io_table, tables_layout[2]);
 }
 
 fn render_system_info(frame: &mut Frame, app: &App, area: Rect) {
     let theme = &app.theme;
     let proc = &app.collectors.process.data;
+    let irq = &app.collectors.interrupts.data;
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -520,6 +1393,12 @@ fn render_system_info(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(if proc.zombies > 0 { theme.danger } else { theme.success })
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Ctx switches: ", Style::default().fg(theme.muted)),
+            Span::styled(format!("{}/s", format_rate(irq.context_switch_rate)), Style::default().fg(theme.primary)),
+            Span::styled(" │ Forks: ", Style::default().fg(theme.muted)),
+            Span::styled(format!("{}/s", format_rate(irq.fork_rate)), Style::default().fg(theme.primary)),
+        ]),
     ]).block(Block::default().padding(ratatui::widgets::Padding::horizontal(1)));
 
     frame.render_widget(info, inner);
@@ -539,13 +1418,42 @@ fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("elp ", Style::default().fg(theme.muted)),
         Span::styled("[+/-]", Style::default().fg(theme.accent)),
         Span::styled("Rate ", Style::default().fg(theme.muted)),
+        Span::styled("[Tab]", Style::default().fg(theme.accent)),
+        Span::styled(format!("{} ", app.view.title()), Style::default().fg(theme.muted)),
     ];
 
+    if app.view == View::Processes {
+        spans.push(Span::styled("[</>]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("Sort ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[I]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("nvert ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[F5]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("Tree ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[k/K]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("Signal ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[N]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("ice ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[/]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("Find ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled("[Enter]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled("Details ", Style::default().fg(theme.muted)));
+    }
+    let enter = match app.view {
+        View::Users | View::Services => Some("Processes "),
+        View::Disks => Some("Disk panel "),
+        _ => None,
+    };
+    if let Some(action) = enter {
+        spans.push(Span::styled("[Enter]", Style::default().fg(theme.accent)));
+        spans.push(Span::styled(action, Style::default().fg(theme.muted)));
+    }
+
     // Show status message or theme info
     if let Some(ref msg) = app.status_message {
+        let color = if msg.starts_with('✗') { theme.danger } else { theme.success };
         spans.push(Span::styled(
             format!("│ {} ", msg),
-            Style::default().fg(theme.success)
+            Style::default().fg(color)
         ));
     } else {
         spans.push(Span::styled(
@@ -565,8 +1473,8 @@ fn render_help_overlay(frame: &mut Frame, app: &App) {
     let area = frame.area();
 
     // Center the popup
-    let popup_width = 50u16.min(area.width.saturating_sub(4));
-    let popup_height = 20u16.min(area.height.saturating_sub(4));
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    let popup_height = 46u16.min(area.height.saturating_sub(4));
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -608,6 +1516,72 @@ fn render_help_overlay(frame: &mut Frame, app: &App) {
             Span::styled("  S          ", Style::default().fg(theme.primary)),
             Span::styled("Save config to file", Style::default().fg(theme.muted)),
         ]),
+        Line::from(vec![
+            Span::styled("  C          ", Style::default().fg(theme.primary)),
+            Span::styled("Process CPU%: per core / whole machine", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Tab / 1-6  ", Style::default().fg(theme.primary)),
+            Span::styled("Switch view (dashboard, procs, users, services, IRQs, disks)", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("PROCESS VIEW", Style::default().fg(theme.accent).bold())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  ↑↓ PgUp/Dn ", Style::default().fg(theme.primary)),
+            Span::styled("Move selection (mouse wheel too)", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  < / >      ", Style::default().fg(theme.primary)),
+            Span::styled("Change sort column", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  I          ", Style::default().fg(theme.primary)),
+            Span::styled("Invert sort order", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  F5         ", Style::default().fg(theme.primary)),
+            Span::styled("Toggle process tree", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Space ← →  ", Style::default().fg(theme.primary)),
+            Span::styled("Fold / collapse / expand subtree", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  k / K      ", Style::default().fg(theme.primary)),
+            Span::styled("Signal process / whole subtree", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  N / O / A  ", Style::default().fg(theme.primary)),
+            Span::styled("Renice / ionice / CPU affinity", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  /          ", Style::default().fg(theme.primary)),
+            Span::styled("Filter (pid: user: cmd: cgroup: re:)", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  X / U      ", Style::default().fg(theme.primary)),
+            Span::styled("Hide kernel threads / only mine", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  w / W      ", Style::default().fg(theme.primary)),
+            Span::styled("Watch PID / process name", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter      ", Style::default().fg(theme.primary)),
+            Span::styled("Process details (/proc/<pid>)", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("USERS / SERVICES / IRQS / DISKS", Style::default().fg(theme.accent).bold())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  ↑↓ PgUp/Dn ", Style::default().fg(theme.primary)),
+            Span::styled("Move selection (Home / End, mouse wheel too)", Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter      ", Style::default().fg(theme.primary)),
+            Span::styled("Show its processes / use disk in dashboard", Style::default().fg(theme.muted)),
+        ]),
         Line::from(""),
         Line::from(Span::styled("THEMES (press T to cycle)", Style::default().fg(theme.accent).bold())),
         Line::from(""),
@@ -643,11 +1617,108 @@ fn render_help_overlay(frame: &mut Frame, app: &App) {
     frame.render_widget(help, popup_area);
 }
 
-/// Truncate string to max length
+fn render_dialog(frame: &mut Frame, app: &App, dialog: &Dialog) {
+    use ratatui::widgets::Clear;
+
+    let theme = &app.theme;
+    let area = frame.area();
+
+    let (title, lines) = match dialog {
+        Dialog::SignalMenu { pid, name, subtree, selected } => {
+            let mut lines = vec![
+                Line::from(Span::styled(
+                    format!("{} ({}){}", truncate_str(name, 24), pid, if *subtree { " + children" } else { "" }),
+                    Style::default().fg(theme.primary).bold(),
+                )),
+                Line::from(""),
+            ];
+            for (i, signal) in Signal::ALL.iter().enumerate() {
+                let style = if i == *selected {
+                    Style::default().fg(theme.accent).reversed()
+                } else {
+                    Style::default().fg(theme.muted)
+                };
+                lines.push(Line::from(Span::styled(format!(" {:<10}", signal.name()), style)));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("↑↓ choose │ Enter select │ Esc cancel", Style::default().fg(theme.muted).italic())));
+            (" SEND SIGNAL ", lines)
+        }
+        Dialog::ConfirmSignal { pid, name, pids, signal } => {
+            let target = if pids.len() > 1 {
+                format!("{} ({}) and {} descendants", truncate_str(name, 20), pid, pids.len() - 1)
+            } else {
+                format!("{} ({})", truncate_str(name, 24), pid)
+            };
+            let lines = vec![
+                Line::from(Span::styled(format!("Send {} to", signal.name()), Style::default().fg(theme.warning).bold())),
+                Line::from(Span::styled(target, Style::default().fg(theme.primary))),
+                Line::from(""),
+                Line::from(Span::styled("[Y]es / [N]o", Style::default().fg(theme.accent))),
+            ];
+            (" CONFIRM ", lines)
+        }
+        Dialog::Input { kind, pid, name, buffer } => {
+            let lines = vec![
+                Line::from(Span::styled(format!("{} ({})", truncate_str(name, 24), pid), Style::default().fg(theme.primary).bold())),
+                Line::from(Span::styled(kind.prompt(), Style::default().fg(theme.muted))),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("> ", Style::default().fg(theme.accent)),
+                    Span::styled(buffer.clone(), Style::default().fg(theme.foreground)),
+                    Span::styled("█", Style::default().fg(theme.accent)),
+                ]),
+                Line::from(""),
+                Line::from(Span::styled("Enter apply │ Esc cancel", Style::default().fg(theme.muted).italic())),
+            ];
+            (" SET ", lines)
+        }
+    };
+
+    let popup_width = 44u16.min(area.width.saturating_sub(4));
+    let popup_height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(popup_width)) / 2,
+        (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    frame.render_widget(Clear, popup_area);
+
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border))
+                .title(title)
+                .title_style(Style::default().fg(theme.primary).bold())
+        )
+        .alignment(ratatui::layout::Alignment::Center);
+
+    frame.render_widget(popup, popup_area);
+}
+
+/// Scroll offset that keeps `selected` within a window of `height` rows
+fn keep_in_view(offset: &Cell<usize>, selected: Option<usize>, len: usize, height: usize) -> usize {
+    let mut first = offset.get().min(len.saturating_sub(height));
+    if let Some(sel) = selected {
+        if sel < first {
+            first = sel;
+        } else if height > 0 && sel >= first + height {
+            first = sel + 1 - height;
+        }
+    }
+    offset.set(first);
+    first
+}
+
+/// Truncate string to max length (in characters)
 fn truncate_str(s: &str, max: usize) -> String {
-    if s.len() <= max {
+    if s.chars().count() <= max {
         s.to_string()
     } else {
-        format!("{}…", &s[..max-1])
+        let head: String = s.chars().take(max.saturating_sub(1)).collect();
+        format!("{}…", head)
     }
 }
@@ -0,0 +1,345 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::app::App;
+use crate::collectors::disk::{format_space, Filesystem};
+use crate::collectors::interrupts::format_rate;
+use crate::collectors::memory::format_bytes;
+use crate::collectors::network::format_speed;
+use crate::ui::{keep_in_view, raid_indicator, read_only_indicator, truncate_str};
+
+/// Width of the USE% bar
+const BAR_WIDTH: usize = 20;
+
+/// Most block devices listed under the filesystems
+const MAX_DEVICES: usize = 12;
+
+/// Disks view: every mounted filesystem with its usage, then RAID arrays and per-device I/O
+pub fn render_disk_view(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let disk = &app.collectors.disk;
+    let filesystems = &disk.filesystems;
+    let list = &app.disk_view;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Line::from(vec![
+            Span::raw(format!(" DISKS ─ {} filesystems ", filesystems.len())),
+            raid_indicator(app),
+            read_only_indicator(app),
+        ]));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let device_rows = disk.devices.len().min(MAX_DEVICES) as u16;
+    let raid_rows = disk.raid.len() as u16;
+    let [table_area, detail_area, raid_area, device_area, footer_area] = Layout::vertical([
+        Constraint::Min(3),
+        Constraint::Length(1),
+        Constraint::Length(if raid_rows > 0 { raid_rows + 1 } else { 0 }),
+        Constraint::Length(if device_rows > 0 { device_rows + 2 } else { 0 }),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let keys = app.list_keys();
+    let selected = list.selected_row(&keys);
+    let height = table_area.height.saturating_sub(1) as usize;
+    list.page_size.set(height);
+    let offset = keep_in_view(&list.scroll_offset, selected, filesystems.len(), height);
+
+    let rows: Vec<Row> = filesystems
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, fs)| {
+            let summary = fs.mount_point == disk.data.mount_point;
+            let text_color = if fs.stale || fs.unreadable { theme.muted } else { theme.foreground };
+            let usage_color = theme.usage_color(fs.usage_percent);
+            let filled = ((fs.usage_percent / 100.0 * BAR_WIDTH as f32).round() as usize).min(BAR_WIDTH);
+            let usage = vec![
+                Span::styled("█".repeat(filled), Style::default().fg(usage_color)),
+                Span::styled("░".repeat(BAR_WIDTH - filled), Style::default().fg(theme.bar_empty)),
+                Span::styled(format!(" {:5.1}%", fs.usage_percent), Style::default().fg(usage_color)),
+            ];
+            let inodes = match fs.inode_percent() {
+                Some(pct) => Span::styled(format!("{:5.1}%", pct), Style::default().fg(theme.usage_color(pct))),
+                None => Span::styled("    -", Style::default().fg(theme.muted)),
+            };
+            let state = if fs.remounted_ro {
+                Span::styled("READ-ONLY", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD | Modifier::REVERSED))
+            } else if fs.stale {
+                Span::styled("not responding", Style::default().fg(theme.warning))
+            } else if fs.unreadable {
+                Span::styled("unreadable", Style::default().fg(theme.warning))
+            } else if fs.read_only {
+                Span::styled("ro", Style::default().fg(theme.muted))
+            } else {
+                Span::raw("")
+            };
+            let mount_color = if fs.remounted_ro { theme.warning } else { theme.primary };
+            let row = Row::new(vec![
+                Cell::from(Span::styled(if summary { "▶" } else { " " }, Style::default().fg(theme.accent))),
+                Cell::from(Span::styled(fs.device.clone(), Style::default().fg(theme.muted))),
+                Cell::from(Span::styled(fs.mount_point.clone(), Style::default().fg(mount_color))),
+                Cell::from(Span::styled(fs.fs_type.clone(), Style::default().fg(theme.secondary))),
+                Cell::from(Span::styled(format!("{:>9}", format_size(fs.total_space)), Style::default().fg(text_color))),
+                Cell::from(Span::styled(format!("{:>9}", format_size(fs.used_space)), Style::default().fg(text_color))),
+                Cell::from(Span::styled(format!("{:>9}", format_size(fs.free_space)), Style::default().fg(text_color))),
+                Cell::from(Line::from(usage)),
+                Cell::from(inodes),
+                Cell::from(state),
+            ]);
+            if Some(i) == selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(1),
+            Constraint::Min(16),
+            Constraint::Min(16),
+            Constraint::Length(10),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(BAR_WIDTH as u16 + 7),
+            Constraint::Length(6),
+            Constraint::Length(14),
+        ],
+    )
+    .header(
+        Row::new(vec!["", "DEVICE", "MOUNT", "TYPE", "     SIZE", "     USED", "     FREE", "USE%", "IUSE%", ""])
+            .style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(table, table_area);
+
+    if let Some(fs) = selected.and_then(|i| filesystems.get(i)) {
+        render_mount_details(frame, app, fs, detail_area);
+    }
+    render_raid(frame, app, raid_area);
+    render_device_io(frame, app, device_area);
+
+    let mut footer = vec![
+        Span::styled(" Enter", Style::default().fg(theme.primary)),
+        Span::styled(": show in the disk panel", Style::default().fg(theme.muted)),
+    ];
+    if disk.hidden > 0 {
+        footer.push(Span::styled(
+            format!(" │ {} hidden by filesystem_exclude", disk.hidden),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(footer)), footer_area);
+
+    if filesystems.is_empty() {
+        let message = Paragraph::new(Span::styled(
+            truncate_str(" No filesystems mounted (or all excluded)", table_area.width as usize),
+            Style::default().fg(theme.muted),
+        ));
+        frame.render_widget(message, table_area);
+    }
+}
+
+/// Options and inode counts of the selected filesystem
+fn render_mount_details(frame: &mut Frame, app: &App, fs: &Filesystem, area: Rect) {
+    let theme = &app.theme;
+    let muted = Style::default().fg(theme.muted);
+    let mut spans = vec![
+        Span::styled(" options ", muted),
+        Span::styled(fs.mount_options.clone(), Style::default().fg(theme.foreground)),
+    ];
+    if !fs.super_options.is_empty() {
+        spans.push(Span::styled(" │ fs ", muted));
+        spans.push(Span::styled(fs.super_options.clone(), Style::default().fg(theme.foreground)));
+    }
+    if fs.inodes_total > 0 {
+        spans.push(Span::styled(
+            format!(
+                " │ inodes {} used of {}, {} free",
+                format_rate(fs.inodes_total.saturating_sub(fs.inodes_free) as f64),
+                format_rate(fs.inodes_total as f64),
+                format_rate(fs.inodes_free as f64),
+            ),
+            muted,
+        ));
+    }
+    if fs.error_count > 0 {
+        spans.push(Span::styled(format!(" │ {} fs errors since fsck", fs.error_count), Style::default().fg(theme.warning)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// One line per md array: state, members and resync progress
+fn render_raid(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    if area.height == 0 {
+        return;
+    }
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme.border))
+        .title(" RAID ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines: Vec<Line> = app.collectors.disk.raid
+        .iter()
+        .map(|array| {
+            let degraded = array.degraded();
+            let name_style = if degraded {
+                Style::default().fg(theme.danger).add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(theme.primary)
+            };
+            let (state, state_color) = match (array.active, degraded) {
+                (false, _) => ("inactive", theme.warning),
+                (true, true) => ("DEGRADED", theme.danger),
+                (true, false) => ("active", theme.foreground),
+            };
+            let mut spans = vec![
+                Span::styled(format!(" {} ", array.name), name_style),
+                Span::styled(format!(" {:<7}", array.level), Style::default().fg(theme.secondary)),
+                Span::styled(format!("{:<9}", state), Style::default().fg(state_color)),
+            ];
+            if array.expected > 0 {
+                spans.push(Span::styled(
+                    format!("[{}/{}] [{}]  ", array.expected, array.working, array.slots),
+                    Style::default().fg(if degraded { theme.danger } else { theme.muted }),
+                ));
+            }
+            for member in &array.members {
+                let (suffix, color) = if member.failed {
+                    ("(F)", theme.danger)
+                } else if member.spare {
+                    ("(S)", theme.muted)
+                } else {
+                    ("", theme.foreground)
+                };
+                spans.push(Span::styled(format!("{}{} ", member.name, suffix), Style::default().fg(color)));
+            }
+            if let Some(sync) = &array.sync {
+                let filled = ((sync.percent / 10.0).round() as usize).min(10);
+                spans.push(Span::styled(format!(" {} ", sync.action), Style::default().fg(theme.warning)));
+                spans.push(Span::styled("█".repeat(filled), Style::default().fg(theme.warning)));
+                spans.push(Span::styled("░".repeat(10 - filled), Style::default().fg(theme.bar_empty)));
+                spans.push(Span::styled(format!(" {:.1}%", sync.percent), Style::default().fg(theme.warning)));
+                if let Some(finish) = &sync.finish {
+                    spans.push(Span::styled(format!(" finish {}", finish), Style::default().fg(theme.muted)));
+                }
+                if let Some(speed) = sync.speed {
+                    spans.push(Span::styled(format!(" {}", format_speed(speed)), Style::default().fg(theme.muted)));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Per-device rates, latency and utilization, like `iostat -x`
+fn render_device_io(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let disk = &app.collectors.disk;
+    if area.height == 0 {
+        return;
+    }
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme.border))
+        .title(" BLOCK DEVICES ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let summary = disk.summary_io().map(|io| io.name.as_str());
+    let rows: Vec<Row> = disk
+        .devices
+        .iter()
+        .take(MAX_DEVICES)
+        .map(|io| {
+            let idle = io.read_iops + io.write_iops == 0.0 && io.in_flight == 0;
+            let text_color = if idle { theme.muted } else { theme.foreground };
+            let text = |value: String| Cell::from(Span::styled(value, Style::default().fg(text_color)));
+            let util_color = theme.usage_color(io.util_percent);
+            let filled = ((io.util_percent / 10.0).round() as usize).min(10);
+            let block = disk.block_devices.get(&io.name);
+            let name = match block.and_then(|b| b.alias.as_ref()) {
+                Some(alias) => format!("{} {}", io.name, alias),
+                None => io.name.clone(),
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(if summary == Some(io.name.as_str()) { "▶" } else { " " }, Style::default().fg(theme.accent))),
+                Cell::from(Span::styled(name, Style::default().fg(theme.primary))),
+                Cell::from(Span::styled(disk.describe_stack(&io.name).unwrap_or_default(), Style::default().fg(theme.secondary))),
+                text(format!("{:>7}", format_rate(io.read_iops))),
+                text(format!("{:>7}", format_rate(io.write_iops))),
+                text(format!("{:>11}", format_speed(io.read_speed))),
+                text(format!("{:>11}", format_speed(io.write_speed))),
+                text(format!("{:>7}", format_rate(io.read_merged))),
+                text(format!("{:>7}", format_rate(io.write_merged))),
+                text(format!("{:>8.2}", io.read_await)),
+                text(format!("{:>8.2}", io.write_await)),
+                text(format!("{:>6.2}", io.queue_depth)),
+                text(format!("{:>5}", io.in_flight)),
+                Cell::from(Line::from(vec![
+                    Span::styled("█".repeat(filled), Style::default().fg(util_color)),
+                    Span::styled("░".repeat(10 - filled), Style::default().fg(theme.bar_empty)),
+                    Span::styled(format!(" {:5.1}%", io.util_percent), Style::default().fg(util_color)),
+                ])),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(1),
+            Constraint::Min(10),
+            Constraint::Min(12),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(6),
+            Constraint::Length(5),
+            Constraint::Length(18),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            "", "DEVICE", "STACK", "    r/s", "    w/s", "     READ/s", "    WRITE/s", " rrqm/s", " wrqm/s",
+            " r_await", " w_await", "aqu-sz", "INFLT", "%UTIL",
+        ])
+        .style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(table, inner);
+}
+
+/// Small filesystems (tmpfs, /boot) need more than whole gigabytes
+fn format_size(bytes: u64) -> String {
+    const GB: u64 = 1024 * 1024 * 1024;
+    if bytes >= 10 * GB {
+        format_space(bytes)
+    } else {
+        format_bytes(bytes)
+    }
+}
@@ -0,0 +1,170 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::app::App;
+use crate::collectors::interrupts::format_rate;
+use crate::ui::{keep_in_view, truncate_str};
+
+/// Widest per-CPU strip; larger machines fold neighbouring CPUs into one column
+const MAX_STRIP: usize = 32;
+
+/// IRQ view: interrupt lines busiest first, with their spread over CPUs
+pub fn render_interrupt_view(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let data = &app.collectors.interrupts.data;
+    let irqs = &data.irqs;
+    let list = &app.irq_view;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(format!(
+            " IRQs ─ {} intr/s │ {} ctxt/s │ {} forks/s │ {} running, {} blocked ",
+            format_rate(data.interrupt_rate),
+            format_rate(data.context_switch_rate),
+            format_rate(data.fork_rate),
+            data.procs_running,
+            data.procs_blocked,
+        ));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [table_area, detail_area, softirq_area, cpu_area] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let keys = app.list_keys();
+    let selected = list.selected_row(&keys);
+    let height = table_area.height.saturating_sub(1) as usize;
+    list.page_size.set(height);
+    let offset = keep_in_view(&list.scroll_offset, selected, irqs.len(), height);
+
+    let strip_width = data.cpus.len().clamp(4, MAX_STRIP);
+    let rows: Vec<Row> = irqs
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, irq)| {
+            let idle = irq.rate == 0.0;
+            let text_color = if idle { theme.muted } else { theme.foreground };
+            let (top_text, top_color) = match irq.busiest_cpu() {
+                Some((cpu, share)) => {
+                    // A busy line pinned to one CPU out of several is the usual affinity problem
+                    let pinned = share >= 90.0 && irq.rate >= 1000.0 && data.cpus.len() > 1;
+                    (
+                        format!("cpu{:<3} {:3.0}%", data.cpus.get(cpu).copied().unwrap_or(cpu as u32), share),
+                        if pinned { theme.warning } else { theme.foreground },
+                    )
+                }
+                None => ("-".to_string(), theme.muted),
+            };
+            let row = Row::new(vec![
+                Cell::from(Span::styled(format!("{:>6}", irq.irq), Style::default().fg(theme.primary))),
+                Cell::from(Span::styled(format!("{:>8}", format_rate(irq.rate)), Style::default().fg(text_color))),
+                Cell::from(Span::styled(distribution(&irq.per_cpu, strip_width), Style::default().fg(theme.graph_line))),
+                Cell::from(Span::styled(top_text, Style::default().fg(top_color))),
+                Cell::from(Span::styled(
+                    irq.affinity.clone().unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(theme.muted),
+                )),
+                Cell::from(Span::styled(irq.device.clone(), Style::default().fg(text_color))),
+                Cell::from(Span::styled(irq.chip.clone(), Style::default().fg(theme.muted))),
+            ]);
+            if Some(i) == selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(strip_width as u16),
+            Constraint::Length(11),
+            Constraint::Length(10),
+            Constraint::Min(20),
+            Constraint::Length(32),
+        ],
+    )
+    .header(
+        Row::new(vec!["   IRQ", "  RATE/s", "CPUS", "TOP CPU", "AFFINITY", "DEVICE", "CHIP"])
+            .style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(table, table_area);
+
+    // Selected line, one rate per CPU
+    let mut detail = vec![Span::styled(" per CPU: ", Style::default().fg(theme.muted))];
+    if let Some(irq) = selected.and_then(|i| irqs.get(i)) {
+        detail.push(Span::styled(format!("{} ", irq.irq), Style::default().fg(theme.primary)));
+        detail.extend(rate_spans(app, &data.cpus, &irq.per_cpu));
+    }
+    frame.render_widget(Paragraph::new(Line::from(detail)), detail_area);
+
+    let mut softirqs = vec![Span::styled(" softirq: ", Style::default().fg(theme.muted))];
+    for softirq in data.softirqs.iter().filter(|s| s.rate > 0.0) {
+        softirqs.push(Span::styled(format!("{} ", softirq.name), Style::default().fg(theme.secondary)));
+        softirqs.push(Span::styled(format!("{} ", format_rate(softirq.rate)), Style::default().fg(theme.foreground)));
+        if softirq.per_cpu.len() > 1 {
+            let strip = distribution(&softirq.per_cpu, softirq.per_cpu.len().min(8));
+            softirqs.push(Span::styled(strip, Style::default().fg(theme.graph_line)));
+        }
+        softirqs.push(Span::raw("  "));
+    }
+    frame.render_widget(Paragraph::new(Line::from(softirqs)), softirq_area);
+
+    let mut totals = vec![Span::styled(" all IRQs: ", Style::default().fg(theme.muted))];
+    totals.extend(rate_spans(app, &data.cpus, &data.per_cpu_rate));
+    frame.render_widget(Paragraph::new(Line::from(totals)), cpu_area);
+
+    if irqs.is_empty() {
+        let message = Paragraph::new(Span::styled(
+            truncate_str(" /proc/interrupts is not readable", table_area.width as usize),
+            Style::default().fg(theme.muted),
+        ));
+        frame.render_widget(message, table_area);
+    }
+}
+
+/// "cpu0 1.2K  cpu1 80 ..." for the CPUs that saw any interrupts
+fn rate_spans<'a>(app: &App, cpus: &[u32], rates: &[f64]) -> Vec<Span<'a>> {
+    let theme = &app.theme;
+    let mut spans = Vec::new();
+    for (cpu, &rate) in cpus.iter().zip(rates).filter(|(_, &r)| r > 0.0) {
+        spans.push(Span::styled(format!("cpu{} ", cpu), Style::default().fg(theme.muted)));
+        spans.push(Span::styled(format!("{}  ", format_rate(rate)), Style::default().fg(theme.foreground)));
+    }
+    spans
+}
+
+/// One shade per CPU (or group of CPUs) scaled to the busiest one
+fn distribution(per_cpu: &[f64], width: usize) -> String {
+    const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+    let group = per_cpu.len().div_ceil(width).max(1);
+    let columns: Vec<f64> = per_cpu.chunks(group).map(|c| c.iter().sum()).collect();
+    let max = columns.iter().cloned().fold(0.0, f64::max);
+    columns
+        .iter()
+        .map(|&rate| {
+            if rate <= 0.0 || max <= 0.0 {
+                '·'
+            } else {
+                SHADES[((rate / max * 4.0).ceil() as usize).clamp(1, 4)]
+            }
+        })
+        .collect()
+}
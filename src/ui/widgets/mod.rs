@@ -4,4 +4,6 @@
 // - gpu_widget.rs
 // - network_widget.rs
 // - disk_widget.rs
-// - process_widget.rs
+pub mod disk_widget;
+pub mod interrupt_widget;
+pub mod process_widget;
@@ -0,0 +1,476 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::CpuMode;
+use crate::collectors::memory::format_bytes;
+use crate::collectors::process::{format_proc_memory, format_proc_rate, InspectedProcess, ProcessFilter, SortColumn};
+use crate::ui::{keep_in_view, truncate_str};
+
+/// Process view: the table, plus the detail pane when one is open
+pub fn render_process_view(frame: &mut Frame, app: &App, area: Rect) {
+    match app.collectors.process.inspected {
+        Some(ref inspected) => {
+            let [table, details] = Layout::horizontal([
+                Constraint::Percentage(55),
+                Constraint::Percentage(45),
+            ])
+            .areas(area);
+            render_process_table(frame, app, table);
+            render_process_details(frame, app, inspected, details);
+        }
+        None => render_process_table(frame, app, area),
+    }
+}
+
+/// Full-screen process table with sorting and selection
+fn render_process_table(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let data = &app.collectors.process.data;
+    let view = &app.process_view;
+
+    let rows = app.process_rows();
+    let total_memory = app.collectors.memory.data.total;
+    let selected = app.selected_row(&rows);
+
+    let mut title = format!(
+        " PROCESSES ─ {} │ Thr: {} │ Run: {} │ Zomb: {} │ Sort: {} {}{}{} ",
+        data.total_processes,
+        data.total_threads,
+        data.running,
+        data.zombies,
+        view.sort_column.label(),
+        if view.sort_descending { "▼" } else { "▲" },
+        if view.tree_mode { " │ Tree" } else { "" },
+        if app.collectors.process.cpu_mode == CpuMode::Solaris { " │ Solaris" } else { "" }
+    );
+    if app.process_filter_active() {
+        let mut active = Vec::new();
+        if view.filter.is_active() {
+            active.push(format!("\"{}\"", view.filter_input));
+        }
+        if view.hide_kernel_threads {
+            active.push("no kthreads".to_string());
+        }
+        if view.only_own_user {
+            active.push("own user".to_string());
+        }
+        title.push_str(&format!(
+            "│ Filter: {} ({} shown) ",
+            active.join(", "),
+            app.filtered_processes().len()
+        ));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(title);
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Search prompt takes the last line while it is open or holds text
+    let show_prompt = view.filter_editing || !view.filter_input.is_empty();
+    let (inner, prompt_area) = if show_prompt && inner.height > 2 {
+        let [table, prompt] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+        (table, Some(prompt))
+    } else {
+        (inner, None)
+    };
+
+    if let Some(prompt_area) = prompt_area {
+        let mut spans = vec![
+            Span::styled("/", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+            Span::styled(view.filter_input.clone(), Style::default().fg(theme.foreground)),
+        ];
+        if view.filter_editing {
+            spans.push(Span::styled("█", Style::default().fg(theme.accent)));
+        }
+        if let ProcessFilter::Invalid(ref err) = view.filter {
+            let summary = err.lines().last().unwrap_or_default();
+            spans.push(Span::styled(format!("  {}", summary), Style::default().fg(theme.danger)));
+        } else if view.filter_editing {
+            spans.push(Span::styled(
+                "  text │ pid:N │ user:NAME │ cmd:TEXT │ cgroup:PATH │ re:REGEX",
+                Style::default().fg(theme.muted),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), prompt_area);
+    }
+    // Keep the selected row on screen (header takes one line)
+    let height = inner.height.saturating_sub(1) as usize;
+    view.page_size.set(height);
+    let offset = keep_in_view(&view.scroll_offset, selected, rows.len(), height);
+
+    let header_cells: Vec<Span> = SortColumn::ALL
+        .iter()
+        .map(|&col| {
+            if col == view.sort_column {
+                let arrow = if view.sort_descending { "▼" } else { "▲" };
+                Span::styled(
+                    format!("{}{}", col.label(), arrow),
+                    Style::default().fg(theme.primary).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(col.label(), Style::default().fg(theme.accent))
+            }
+        })
+        .collect();
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, r)| {
+            let p = r.info;
+            // Collapsed nodes stand in for their whole subtree
+            let (cpu, memory) = if r.collapsed {
+                (r.subtree_cpu, r.subtree_memory)
+            } else {
+                (p.cpu_percent, p.memory_bytes)
+            };
+            let memory_percent = if r.collapsed && total_memory > 0 {
+                (memory as f32 / total_memory as f32) * 100.0
+            } else {
+                p.memory_percent
+            };
+            let marker = match (r.has_children, r.collapsed) {
+                (true, true) => "+ ",
+                (true, false) => "- ",
+                _ if view.tree_mode => "  ",
+                _ => "",
+            };
+
+            let row = Row::new(vec![
+                Cell::from(Span::styled(format!("{:>7}", p.pid), Style::default().fg(theme.muted))),
+                Cell::from(Line::from(vec![
+                    Span::styled(r.prefix.clone(), Style::default().fg(theme.border)),
+                    Span::styled(marker, Style::default().fg(theme.accent)),
+                    Span::styled(p.name.clone(), Style::default().fg(theme.primary)),
+                    Span::styled(
+                        if app.collectors.process.watch_of(p.pid).is_some() { " ◉" } else { "" },
+                        Style::default().fg(theme.accent),
+                    ),
+                ])),
+                Cell::from(Span::styled(
+                    format!("{:5.1}%", cpu),
+                    Style::default().fg(theme.usage_color(app.collectors.process.cpu_share(cpu))),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:>6} {:4.1}%", format_proc_memory(memory), memory_percent),
+                    Style::default().fg(theme.usage_color(memory_percent)),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:>6}/{:<6}", format_proc_rate(p.read_rate), format_proc_rate(p.write_rate)),
+                    Style::default().fg(if p.io_rate() > 0.0 { theme.accent } else { theme.muted }),
+                )),
+                Cell::from(Span::styled(format!("{:>4}", p.threads), Style::default().fg(theme.muted))),
+                Cell::from(Span::styled(truncate_str(&p.status, 10), Style::default().fg(theme.muted))),
+            ]);
+            if Some(i) == selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        table_rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(16),
+            Constraint::Length(7),
+            Constraint::Length(13),
+            Constraint::Length(13),
+            Constraint::Length(5),
+            Constraint::Length(10),
+        ],
+    )
+    .header(Row::new(header_cells));
+    frame.render_widget(table, inner);
+}
+
+/// Detail pane for one process: identity, scheduling, live CPU/RSS graphs, environment
+fn render_process_details(frame: &mut Frame, app: &App, inspected: &InspectedProcess, area: Rect) {
+    let theme = &app.theme;
+    let d = &inspected.details;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(if inspected.exited { theme.danger } else { theme.border }))
+        .title(format!(
+            " PID {} ─ {}{} ",
+            inspected.pid,
+            truncate_str(&d.name, 24),
+            if inspected.exited { " ─ EXITED" } else { "" }
+        ));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [info_area, cpu_area, rss_area, env_area] = Layout::vertical([
+        Constraint::Length(9),
+        Constraint::Length(4),
+        Constraint::Length(4),
+        Constraint::Min(1),
+    ])
+    .areas(inner);
+
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<8}", label), Style::default().fg(theme.muted)),
+            Span::styled(value, Style::default().fg(theme.primary)),
+        ])
+    };
+    let or_dash = |v: &Option<String>| v.clone().unwrap_or_else(|| "-".to_string());
+
+    let started = chrono::DateTime::from_timestamp(d.start_time as i64, 0)
+        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let ids = format!(
+        "{} (uid {} / gid {})",
+        if d.user.is_empty() { "?" } else { &d.user },
+        d.uid.map(|u| u.to_string()).unwrap_or_else(|| "-".to_string()),
+        d.gid.map(|g| g.to_string()).unwrap_or_else(|| "-".to_string())
+    );
+    let sched = format!(
+        "nice {} │ {} │ {} fds",
+        d.nice.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string()),
+        d.policy.unwrap_or("-"),
+        d.fd_count.map(|n| n.to_string()).unwrap_or_else(|| "?".to_string())
+    );
+
+    let info = Paragraph::new(vec![
+        field("Cmd", d.command.join(" ")),
+        field("Exe", or_dash(&d.exe)),
+        field("Cwd", or_dash(&d.cwd)),
+        field("Started", started),
+        field("User", ids),
+        field("Sched", sched),
+        field("Cgroup", or_dash(&d.cgroup)),
+    ])
+    .wrap(Wrap { trim: false });
+    frame.render_widget(info, info_area);
+
+    // Live graphs
+    let cpu_history = inspected.cpu_history.as_vec();
+    let cpu_now = cpu_history.last().copied().unwrap_or(0);
+    let cpu_max = cpu_history.iter().max().copied().unwrap_or(0).max(100);
+    let cpu_spark = Sparkline::default()
+        .block(Block::default().title(format!("CPU {}%", cpu_now)).title_style(Style::default().fg(theme.muted)))
+        .data(&cpu_history)
+        .max(cpu_max)
+        .style(Style::default().fg(theme.usage_color(app.collectors.process.cpu_share(cpu_now as f32))));
+    frame.render_widget(cpu_spark, cpu_area);
+
+    let rss_history = inspected.rss_history.as_vec();
+    let rss_now = rss_history.last().copied().unwrap_or(0);
+    let rss_max = rss_history.iter().max().copied().unwrap_or(1).max(1);
+    let rss_spark = Sparkline::default()
+        .block(Block::default().title(format!("RSS {} MB", rss_now)).title_style(Style::default().fg(theme.muted)))
+        .data(&rss_history)
+        .max(rss_max)
+        .style(Style::default().fg(theme.graph_line));
+    frame.render_widget(rss_spark, rss_area);
+
+    // Environment fills whatever space is left
+    let mut env_lines = vec![Line::from(Span::styled(
+        format!("Environment ({})", d.environ.len()),
+        Style::default().fg(theme.accent),
+    ))];
+    env_lines.extend(d.environ.iter().map(|var| {
+        Line::from(Span::styled(var.clone(), Style::default().fg(theme.muted)))
+    }));
+    frame.render_widget(Paragraph::new(env_lines), env_area);
+}
+
+/// Per-user totals; Enter shows that user's processes
+pub fn render_user_view(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let users = &app.collectors.process.data.users;
+    let list = &app.user_view;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(format!(" USERS ─ {} │ Enter: show processes ", users.len()));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let keys = app.list_keys();
+    let selected = list.selected_row(&keys);
+    let height = inner.height.saturating_sub(1) as usize;
+    list.page_size.set(height);
+    let offset = keep_in_view(&list.scroll_offset, selected, users.len(), height);
+
+    let total_memory = app.collectors.memory.data.total;
+    let rows: Vec<Row> = users
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, u)| {
+            let memory_percent = if total_memory > 0 {
+                (u.memory_bytes as f32 / total_memory as f32) * 100.0
+            } else {
+                0.0
+            };
+            let row = Row::new(vec![
+                Cell::from(Span::styled(
+                    if u.user.is_empty() { "?".to_string() } else { u.user.clone() },
+                    Style::default().fg(theme.primary),
+                )),
+                Cell::from(Span::styled(
+                    u.uid.map(|n| n.to_string()).unwrap_or_default(),
+                    Style::default().fg(theme.muted),
+                )),
+                Cell::from(Span::styled(format!("{:>6}", u.processes), Style::default().fg(theme.muted))),
+                Cell::from(Span::styled(
+                    format!("{:6.1}%", u.cpu_percent),
+                    Style::default().fg(theme.usage_color(app.collectors.process.cpu_share(u.cpu_percent))),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:>8} {:4.1}%", format_bytes(u.memory_bytes), memory_percent),
+                    Style::default().fg(theme.usage_color(memory_percent)),
+                )),
+                Cell::from(Span::styled(format!("{:>7}", u.threads), Style::default().fg(theme.muted))),
+            ]);
+            if Some(i) == selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Length(15),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new(vec!["USER", "UID", " PROCS", "   CPU%", "     RSS", "THREADS"])
+            .style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(table, inner);
+}
+
+/// Services view: processes grouped by cgroup (systemd unit, container, pod)
+pub fn render_service_view(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
+    let process = &app.collectors.process;
+    let groups = &process.data.cgroups;
+    let list = &app.service_view;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(format!(" SERVICES ─ {} cgroups │ Enter: show processes ", groups.len()));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [table_area, path_area] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    let keys = app.list_keys();
+    let selected = list.selected_row(&keys);
+    let height = table_area.height.saturating_sub(1) as usize;
+    list.page_size.set(height);
+    let offset = keep_in_view(&list.scroll_offset, selected, groups.len(), height);
+
+    let rows: Vec<Row> = groups
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, g)| {
+            // memory.current includes page cache, so it can exceed the RSS sum
+            let (limit_text, limit_color) = match (g.memory_current, g.memory_max) {
+                (Some(current), Some(max)) if max > 0 => {
+                    let pct = (current as f32 / max as f32) * 100.0;
+                    (
+                        format!("{:>8} / {:<8}", format_bytes(current), format_bytes(max)),
+                        theme.usage_color(pct),
+                    )
+                }
+                (Some(current), _) => (format!("{:>8} / {:<8}", format_bytes(current), "max"), theme.muted),
+                _ => ("-".to_string(), theme.muted),
+            };
+            let throttled = if g.nr_throttled > 0 {
+                format!("{:5.1}ms/s {}/{}", g.throttled_ms_per_sec, g.nr_throttled, g.nr_periods)
+            } else {
+                "-".to_string()
+            };
+            let throttle_color = if g.throttled_ms_per_sec > 0.0 { theme.warning } else { theme.muted };
+            let row = Row::new(vec![
+                Cell::from(Span::styled(g.name.clone(), Style::default().fg(theme.primary))),
+                Cell::from(Span::styled(g.kind.label(), Style::default().fg(theme.muted))),
+                Cell::from(Span::styled(format!("{:>6}", g.processes), Style::default().fg(theme.muted))),
+                Cell::from(Span::styled(
+                    format!("{:6.1}%", g.cpu_percent),
+                    Style::default().fg(theme.usage_color(process.cpu_share(g.cpu_percent))),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:>8}", format_bytes(g.memory_bytes)),
+                    Style::default().fg(theme.foreground),
+                )),
+                Cell::from(Span::styled(limit_text, Style::default().fg(limit_color))),
+                Cell::from(Span::styled(throttled, Style::default().fg(throttle_color))),
+            ]);
+            if Some(i) == selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(8),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(20),
+            Constraint::Length(24),
+        ],
+    )
+    .header(
+        Row::new(vec!["NAME", "TYPE", " PROCS", "   CPU%", "     RSS", "  MEM CURRENT / MAX", "THROTTLED"])
+            .style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(table, table_area);
+
+    let footer = if process.has_cgroup_v2() {
+        match selected.and_then(|i| groups.get(i)) {
+            Some(g) => Line::from(vec![
+                Span::styled(" cgroup: ", Style::default().fg(theme.muted)),
+                Span::styled(g.path.clone(), Style::default().fg(theme.foreground)),
+            ]),
+            None => Line::default(),
+        }
+    } else {
+        Line::from(Span::styled(
+            " cgroup v2 not mounted: memory limits and throttling unavailable",
+            Style::default().fg(theme.muted),
+        ))
+    };
+    frame.render_widget(Paragraph::new(footer), path_area);
+}